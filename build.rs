@@ -0,0 +1,133 @@
+//! Validates the weather registry sources in `compile_time_configs` and turns them into a static table,
+//! so that configuration mistakes fail the build instead of the app at startup.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+use serde_json::Value;
+
+/// Same as `wmo_code::MAX_VALUE`, the generated table does not compile if they diverge.
+const MAX_WMO_CODE: u8 = 99;
+
+const CONFIG_DIR: &str = "compile_time_configs";
+const ICON_DIR: &str = "assets/icons";
+const MISSING_ICON: &str = "MISSING";
+
+/// Codes of the locales in `i18n::Locale`, each with a file in `compile_time_configs/weather_descriptions`.
+const LOCALES: [&str; 3] = ["en", "de", "fr"];
+
+struct IconFileNames {
+    day: String,
+    night: Option<String>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={CONFIG_DIR}");
+    println!("cargo:rerun-if-changed={ICON_DIR}");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    let tables = generate_weather_tables().unwrap_or_else(|error| panic!("Invalid weather registry configuration: {error}"));
+
+    for (file_name, table) in tables {
+        fs::write(out_dir.join(file_name), table).unwrap();
+    }
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(file_name: &str) -> Result<T, String> {
+    let path = Path::new(CONFIG_DIR).join(file_name);
+    let json = fs::read_to_string(&path).map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    serde_json::from_str(&json).map_err(|error| format!("Failed to deserialize {}: {error}", path.display()))
+}
+
+/// Icons are either given by a single file name or as `{ "day": ..., "night": ... }` with an optional night icon.
+fn parse_icon_file_names(key: &str, value: Value) -> Result<IconFileNames, String> {
+    let file_name = |value: Option<&Value>, time: &str| match value {
+        Some(Value::String(file_name)) if file_name == MISSING_ICON => Err(format!("The {time} icon for key {key} is still {MISSING_ICON}")),
+        Some(Value::String(file_name)) if !Path::new(ICON_DIR).join(format!("{file_name}.svg")).is_file() => {
+            Err(format!("The {time} icon {file_name} for key {key} does not exist in {ICON_DIR}"))
+        },
+        Some(Value::String(file_name)) => Ok(file_name.clone()),
+        _ => Err(format!("The {time} icon for key {key} must be a file name")),
+    };
+
+    match &value {
+        Value::String(_) => Ok(IconFileNames { day: file_name(Some(&value), "day")?, night: None }),
+        Value::Object(object) => Ok(IconFileNames {
+            day: file_name(object.get("day"), "day")?,
+            night: object.get("night").map(|night| file_name(Some(night), "night")).transpose()?,
+        }),
+        _ => Err(format!("The icon for key {key} must be a file name or an object with day and night file names")),
+    }
+}
+
+fn icon_path(icon_file_name: &str) -> String {
+    format!("{ICON_DIR}/{icon_file_name}.svg")
+}
+
+/// Keys of all WMO codes, indexed by their value.
+fn read_wmo_code_keys() -> Result<Vec<String>, String> {
+    let mut wmo_codes = read_json::<BTreeMap<u8, String>>("wmo_codes.json")?;
+
+    if let Some(wmo_code) = wmo_codes.keys().find(|&&wmo_code| wmo_code > MAX_WMO_CODE) {
+        return Err(format!("WMO code {wmo_code} is out of range, the maximum is {MAX_WMO_CODE}"));
+    }
+
+    (0..=MAX_WMO_CODE)
+        .map(|wmo_code| wmo_codes.remove(&wmo_code).ok_or(format!("WMO code definition missing for code {wmo_code}")))
+        .collect()
+}
+
+/// Returns the file names and contents of the icon table and of one description table per locale.
+fn generate_weather_tables() -> Result<Vec<(String, String)>, String> {
+    let keys = read_wmo_code_keys()?;
+    let valid_keys = keys.iter().collect::<BTreeSet<_>>();
+
+    let icon_file_names = read_json::<BTreeMap<String, Value>>("icon_file_names.json")?;
+
+    if let Some(key) = icon_file_names.keys().find(|key| !valid_keys.contains(key)) {
+        return Err(format!("Icon defined for non-existent key {key}"));
+    }
+
+    let icon_file_names = icon_file_names.into_iter()
+        .map(|(key, value)| parse_icon_file_names(&key, value).map(|icon_file_names| (key, icon_file_names)))
+        .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+    let mut icon_table = String::from("[\n");
+
+    for key in &keys {
+        let icons = icon_file_names.get(key).ok_or(format!("Icon missing for key {key}"))?;
+        let night_icon_path = icons.night.as_deref().map(icon_path);
+
+        writeln!(
+            icon_table,
+            "    WeatherIcons {{ day_icon_path: {:?}, night_icon_path: {:?} }},",
+            icon_path(&icons.day),
+            night_icon_path,
+        ).unwrap();
+    }
+
+    icon_table.push(']');
+    let mut tables = vec![("weather_icons.rs".to_owned(), icon_table)];
+
+    for locale in LOCALES {
+        let descriptions = read_json::<BTreeMap<String, String>>(&format!("weather_descriptions/{locale}.json"))?;
+
+        if let Some(key) = descriptions.keys().find(|key| !valid_keys.contains(key)) {
+            return Err(format!("Description ({locale}) defined for non-existent key {key}"));
+        }
+
+        let mut description_table = String::from("[\n");
+
+        for key in &keys {
+            let description = descriptions.get(key).ok_or(format!("Description ({locale}) missing for key {key}"))?;
+            writeln!(description_table, "    {description:?},").unwrap();
+        }
+
+        description_table.push(']');
+        tables.push((format!("weather_descriptions_{locale}.rs"), description_table));
+    }
+
+    Ok(tables)
+}
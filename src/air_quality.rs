@@ -0,0 +1,172 @@
+use std::sync::Arc;
+use crate::data::{AirQualityIndex, Concentration, Coordinates, PollenCount, PollenSpecies};
+use crate::http::{self, RequestPolicy};
+use crate::util::LocalBoxFuture;
+
+const CURRENT_VARIABLES: &str = "pm2_5,pm10,ozone,nitrogen_dioxide,european_aqi,us_aqi,alder_pollen,birch_pollen,grass_pollen,mugwort_pollen,olive_pollen,ragweed_pollen";
+
+/// Current air quality. Values that are not available at the location are `None`,
+/// e.g. pollen is only forecast for Europe and during the respective season.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AirQuality {
+    pub pm2_5: Option<Concentration>,
+    pub pm10: Option<Concentration>,
+    pub ozone: Option<Concentration>,
+    pub nitrogen_dioxide: Option<Concentration>,
+    pub european_aqi: Option<AirQualityIndex>,
+    pub us_aqi: Option<AirQualityIndex>,
+    pub pollen: Vec<(PollenSpecies, PollenCount)>,
+}
+
+pub trait AirQualitySource {
+    fn current_air_quality(&self, coordinates: Coordinates) -> LocalBoxFuture<'_, Result<AirQuality, Error>>;
+}
+
+/// Open-Meteo air quality API, see https://open-meteo.com/en/docs/air-quality-api.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OpenMeteoAirQuality {
+    pub request_policy: RequestPolicy,
+}
+
+mod api_response {
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    pub struct Current {
+        pub pm2_5: Option<f32>,
+        pub pm10: Option<f32>,
+        pub ozone: Option<f32>,
+        pub nitrogen_dioxide: Option<f32>,
+        pub european_aqi: Option<f32>,
+        pub us_aqi: Option<f32>,
+        pub alder_pollen: Option<f32>,
+        pub birch_pollen: Option<f32>,
+        pub grass_pollen: Option<f32>,
+        pub mugwort_pollen: Option<f32>,
+        pub olive_pollen: Option<f32>,
+        pub ragweed_pollen: Option<f32>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Response {
+        pub current: Current,
+    }
+}
+
+impl AirQualitySource for OpenMeteoAirQuality {
+    fn current_air_quality(&self, coordinates: Coordinates) -> LocalBoxFuture<'_, Result<AirQuality, Error>> {
+        Box::pin(async move {
+            let url = format!(
+                "https://air-quality-api.open-meteo.com/v1/air-quality?latitude={}&longitude={}&current={}&timezone=auto",
+                f64::from(coordinates.latitude),
+                f64::from(coordinates.longitude),
+                CURRENT_VARIABLES,
+            );
+
+            let api_response = http::get(&url, &self.request_policy)
+                .await?
+                .parse::<api_response::Response>()?;
+
+            Ok(convert(api_response.current))
+        })
+    }
+}
+
+fn convert(current: api_response::Current) -> AirQuality {
+    let pollen = [
+        (PollenSpecies::Alder, current.alder_pollen),
+        (PollenSpecies::Birch, current.birch_pollen),
+        (PollenSpecies::Grass, current.grass_pollen),
+        (PollenSpecies::Mugwort, current.mugwort_pollen),
+        (PollenSpecies::Olive, current.olive_pollen),
+        (PollenSpecies::Ragweed, current.ragweed_pollen),
+    ];
+
+    AirQuality {
+        pm2_5: current.pm2_5.map(Concentration::MicrogramsPerCubicMeter),
+        pm10: current.pm10.map(Concentration::MicrogramsPerCubicMeter),
+        ozone: current.ozone.map(Concentration::MicrogramsPerCubicMeter),
+        nitrogen_dioxide: current.nitrogen_dioxide.map(Concentration::MicrogramsPerCubicMeter),
+        european_aqi: current.european_aqi.map(AirQualityIndex::European),
+        us_aqi: current.us_aqi.map(AirQualityIndex::UnitedStates),
+        pollen: pollen.into_iter()
+            .filter_map(|(species, count)| Some((species, PollenCount::GrainsPerCubicMeter(count?))))
+            .collect(),
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The air quality API could not be called successfully: {0}")]
+    ApiCall(Arc<reqwasm::Error>),
+
+    #[error("The air quality API could not be reached: {0}")]
+    Request(#[from] http::Error),
+
+    #[error("The air quality API responded with an error: {0}")]
+    Response(#[from] http::ResponseError),
+}
+
+impl From<reqwasm::Error> for Error {
+    fn from(error: reqwasm::Error) -> Self {
+        Error::ApiCall(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::air_quality::{api_response, convert, Error};
+    use crate::http::{Response, ResponseError};
+    use crate::data::{AirQualityIndex, Concentration, PollenCount, PollenSpecies};
+
+    #[test]
+    fn convert_response() {
+        let json = r#"{
+            "latitude": 52.5,
+            "longitude": 13.4,
+            "current": {
+                "time": "2024-04-20T12:00",
+                "interval": 3600,
+                "pm2_5": 7.3,
+                "pm10": 12.1,
+                "ozone": 84.0,
+                "nitrogen_dioxide": 9.6,
+                "european_aqi": 42,
+                "us_aqi": 57,
+                "alder_pollen": 0.0,
+                "birch_pollen": 153.2,
+                "grass_pollen": null,
+                "mugwort_pollen": null,
+                "olive_pollen": null,
+                "ragweed_pollen": null
+            }
+        }"#;
+
+        let api_response = serde_json::from_str::<api_response::Response>(json).unwrap();
+        let air_quality = convert(api_response.current);
+
+        assert_eq!(air_quality.pm2_5, Some(Concentration::MicrogramsPerCubicMeter(7.3)));
+        assert_eq!(air_quality.european_aqi, Some(AirQualityIndex::European(42.)));
+        assert_eq!(air_quality.us_aqi, Some(AirQualityIndex::UnitedStates(57.)));
+        assert_eq!(air_quality.pollen, vec![
+            (PollenSpecies::Alder, PollenCount::GrainsPerCubicMeter(0.)),
+            (PollenSpecies::Birch, PollenCount::GrainsPerCubicMeter(153.2)),
+        ]);
+    }
+
+    #[test]
+    fn error_responses() {
+        let parse = |status, body: &str| -> Result<api_response::Response, Error> {
+            let response = Response { status, retry_after: None, body: body.to_string() };
+            Ok(response.parse()?)
+        };
+
+        match parse(400, r#"{"error": true, "reason": "Cannot initialize AirQualityVariable from invalid String value pm25"}"#) {
+            Err(Error::Response(ResponseError::Rejected { reason })) => assert_eq!(reason, "Cannot initialize AirQualityVariable from invalid String value pm25"),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+
+        assert!(matches!(parse(429, ""), Err(Error::Response(ResponseError::RateLimited { retry_after: None, reason: None }))));
+        assert!(matches!(parse(502, "Bad Gateway"), Err(Error::Response(ResponseError::UnexpectedStatus { status: 502 }))));
+    }
+}
@@ -0,0 +1,303 @@
+//! Course of the sun after the equations of the NOAA solar calculator, see https://gml.noaa.gov/grad/solcalc/calcdetails.html.
+//! The times are accurate to about a minute within ±72° latitude and become less precise towards the poles.
+
+use crate::data::{Coordinates, Date, DateTime};
+
+const MINUTES_PER_DAY: f64 = 1440.;
+
+/// Zenith angles of the centre of the sun in degrees. Sunrise and sunset refer to the upper limb
+/// touching the horizon, taking the atmospheric refraction into account.
+const SUNRISE_ZENITH: f64 = 90.833;
+const CIVIL_TWILIGHT_ZENITH: f64 = 96.;
+const NAUTICAL_TWILIGHT_ZENITH: f64 = 102.;
+const ASTRONOMICAL_TWILIGHT_ZENITH: f64 = 108.;
+
+/// Times at which the sun passes a certain altitude during a day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Crossing {
+    RiseAndSet { rise: DateTime, set: DateTime },
+
+    /// The sun stays above the altitude all day, as during polar day.
+    AlwaysAbove,
+
+    /// The sun stays below the altitude all day, as during polar night.
+    AlwaysBelow,
+}
+
+impl Crossing {
+    pub fn rise(&self) -> Option<DateTime> {
+        match self {
+            Crossing::RiseAndSet { rise, .. } => Some(*rise),
+            _ => None,
+        }
+    }
+
+    pub fn set(&self) -> Option<DateTime> {
+        match self {
+            Crossing::RiseAndSet { set, .. } => Some(*set),
+            _ => None,
+        }
+    }
+
+    pub fn minutes_above(&self) -> i64 {
+        match self {
+            Crossing::RiseAndSet { rise, set } => set.minutes_since_epoch() - rise.minutes_since_epoch(),
+            Crossing::AlwaysAbove => MINUTES_PER_DAY as i64,
+            Crossing::AlwaysBelow => 0,
+        }
+    }
+}
+
+/// Course of the sun on a day, with all times given in the local time of the day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Daylight {
+    pub solar_noon: DateTime,
+    pub sunrise_and_sunset: Crossing,
+
+    /// Sun less than 6° below the horizon
+    pub civil_twilight: Crossing,
+
+    /// Sun less than 12° below the horizon
+    pub nautical_twilight: Crossing,
+
+    /// Sun less than 18° below the horizon
+    pub astronomical_twilight: Crossing,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolarPosition {
+    /// Degrees above the horizon, corrected for atmospheric refraction
+    pub elevation: f32,
+
+    /// Degrees clockwise from north
+    pub azimuth: f32,
+}
+
+/// Declination in degrees and equation of time in minutes at an instant, given in minutes since the Unix epoch (UTC).
+struct Sun {
+    declination: f64,
+    equation_of_time: f64,
+}
+
+impl Sun {
+    fn at(minutes_since_epoch: f64) -> Self {
+        let julian_day = minutes_since_epoch / MINUTES_PER_DAY + 2_440_587.5;
+        let t = (julian_day - 2_451_545.) / 36_525.;
+
+        let mean_longitude = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.).to_radians();
+        let mean_anomaly = (357.52911 + t * (35999.05029 - 0.0001537 * t)).to_radians();
+        let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+
+        let equation_of_center = mean_anomaly.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+            + (2. * mean_anomaly).sin() * (0.019993 - 0.000101 * t)
+            + (3. * mean_anomaly).sin() * 0.000289;
+
+        let omega = (125.04 - 1934.136 * t).to_radians();
+        let apparent_longitude = (mean_longitude.to_degrees() + equation_of_center - 0.00569 - 0.00478 * omega.sin()).to_radians();
+
+        let mean_obliquity = 23. + (26. + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.) / 60.;
+        let obliquity = (mean_obliquity + 0.00256 * omega.cos()).to_radians();
+
+        let declination = (obliquity.sin() * apparent_longitude.sin()).asin().to_degrees();
+
+        let y = (obliquity / 2.).tan().powi(2);
+        let equation_of_time = 4. * (y * (2. * mean_longitude).sin()
+            - 2. * eccentricity * mean_anomaly.sin()
+            + 4. * eccentricity * y * mean_anomaly.sin() * (2. * mean_longitude).cos()
+            - 0.5 * y * y * (4. * mean_longitude).sin()
+            - 1.25 * eccentricity * eccentricity * (2. * mean_anomaly).sin()).to_degrees();
+
+        Sun { declination, equation_of_time }
+    }
+}
+
+fn degrees(coordinates: Coordinates) -> (f64, f64) {
+    (f64::from(coordinates.latitude), f64::from(coordinates.longitude))
+}
+
+/// Computes the course of the sun on the given local date, at a time zone `utc_offset_minutes` ahead of UTC.
+pub fn daylight(coordinates: Coordinates, date: Date, utc_offset_minutes: i32) -> Daylight {
+    let (latitude, longitude) = degrees(coordinates);
+    let utc_offset = utc_offset_minutes as f64;
+
+    // Starting from local noon, the transit of the sun is refined with its position at the previous estimate
+    let mut solar_noon = date.days_since_epoch() as f64 * MINUTES_PER_DAY + 720. - utc_offset;
+    for _ in 0..2 {
+        solar_noon = nearest_transit(solar_noon, longitude);
+    }
+
+    let local_time = |minutes_since_epoch: f64| DateTime::from_minutes_since_epoch((minutes_since_epoch + utc_offset).round() as i64);
+    let crossing = |zenith| crossing(latitude, solar_noon, zenith, local_time);
+
+    Daylight {
+        solar_noon: local_time(solar_noon),
+        sunrise_and_sunset: crossing(SUNRISE_ZENITH),
+        civil_twilight: crossing(CIVIL_TWILIGHT_ZENITH),
+        nautical_twilight: crossing(NAUTICAL_TWILIGHT_ZENITH),
+        astronomical_twilight: crossing(ASTRONOMICAL_TWILIGHT_ZENITH),
+    }
+}
+
+/// Time of the transit of the sun through the meridian closest to the given time.
+fn nearest_transit(minutes_since_epoch: f64, longitude: f64) -> f64 {
+    let transit_minute_of_day = 720. - 4. * longitude - Sun::at(minutes_since_epoch).equation_of_time;
+    let correction = transit_minute_of_day - minutes_since_epoch.rem_euclid(MINUTES_PER_DAY);
+
+    minutes_since_epoch + (correction + 720.).rem_euclid(MINUTES_PER_DAY) - 720.
+}
+
+/// Each time is refined once with the position of the sun at the previous estimate.
+fn crossing(latitude: f64, solar_noon: f64, zenith: f64, local_time: impl Fn(f64) -> DateTime) -> Crossing {
+    let latitude = latitude.to_radians();
+    let equation_of_time_at_noon = Sun::at(solar_noon).equation_of_time;
+    let mut times = [solar_noon; 2];
+
+    for (time, direction) in times.iter_mut().zip([-1., 1.]) {
+        for _ in 0..2 {
+            let sun = Sun::at(*time);
+            let declination = sun.declination.to_radians();
+            let cos_hour_angle = zenith.to_radians().cos() / (latitude.cos() * declination.cos()) - latitude.tan() * declination.tan();
+
+            if cos_hour_angle > 1. {
+                return Crossing::AlwaysBelow;
+            }
+
+            if cos_hour_angle < -1. {
+                return Crossing::AlwaysAbove;
+            }
+
+            let hour_angle = cos_hour_angle.acos().to_degrees();
+            *time = solar_noon + equation_of_time_at_noon - sun.equation_of_time + direction * 4. * hour_angle;
+        }
+    }
+
+    Crossing::RiseAndSet { rise: local_time(times[0]), set: local_time(times[1]) }
+}
+
+/// Computes the position of the sun in the sky at a time given in UTC.
+pub fn solar_position(coordinates: Coordinates, time: DateTime) -> SolarPosition {
+    let (latitude, longitude) = degrees(coordinates);
+    let minutes_since_epoch = time.minutes_since_epoch() as f64;
+    let sun = Sun::at(minutes_since_epoch);
+
+    let true_solar_time = (minutes_since_epoch + sun.equation_of_time + 4. * longitude).rem_euclid(MINUTES_PER_DAY);
+    let hour_angle = true_solar_time / 4. - 180.;
+
+    let (latitude, declination) = (latitude.to_radians(), sun.declination.to_radians());
+    let cos_zenith = latitude.sin() * declination.sin() + latitude.cos() * declination.cos() * hour_angle.to_radians().cos();
+    let zenith = cos_zenith.clamp(-1., 1.).acos();
+    let elevation = 90. - zenith.to_degrees();
+
+    let cos_azimuth = (latitude.sin() * zenith.cos() - declination.sin()) / (latitude.cos() * zenith.sin());
+    let azimuth = cos_azimuth.clamp(-1., 1.).acos().to_degrees();
+    let azimuth = if hour_angle > 0. { azimuth + 180. } else { 540. - azimuth }.rem_euclid(360.);
+
+    SolarPosition {
+        elevation: (elevation + refraction(elevation)) as f32,
+        azimuth: azimuth as f32,
+    }
+}
+
+/// Approximate atmospheric refraction in degrees for an elevation in degrees.
+fn refraction(elevation: f64) -> f64 {
+    let tan_elevation = elevation.to_radians().tan();
+
+    let arc_seconds = if elevation > 85. {
+        0.
+    } else if elevation > 5. {
+        58.1 / tan_elevation - 0.07 / tan_elevation.powi(3) + 0.000086 / tan_elevation.powi(5)
+    } else if elevation > -0.575 {
+        1735. + elevation * (-518.2 + elevation * (103.4 + elevation * (-12.79 + elevation * 0.711)))
+    } else {
+        -20.772 / tan_elevation
+    };
+
+    arc_seconds / 3600.
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::astronomy::{daylight, solar_position, Crossing};
+    use crate::data::{Date, DateTime};
+    use crate::test_util::coordinates;
+
+    fn date(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    /// NOAA rounds to whole minutes, so a difference of one minute is tolerated.
+    fn assert_time(actual: DateTime, expected: &str) {
+        let expected = expected.parse::<DateTime>().unwrap();
+        assert!((actual.minutes_since_epoch() - expected.minutes_since_epoch()).abs() <= 1, "{actual} != {expected}");
+    }
+
+    fn assert_crossing(actual: Crossing, expected_rise: &str, expected_set: &str) {
+        let Crossing::RiseAndSet { rise, set } = actual else {
+            panic!("Expected the sun to rise and set, but got {actual:?}");
+        };
+
+        assert_time(rise, expected_rise);
+        assert_time(set, expected_set);
+    }
+
+    #[test]
+    fn sunrise_and_sunset() {
+        // London, summer solstice, BST
+        let london = daylight(coordinates(51.5, -0.12), date("2024-06-21"), 60);
+        assert_time(london.solar_noon, "2024-06-21T13:02");
+        assert_crossing(london.sunrise_and_sunset, "2024-06-21T04:43", "2024-06-21T21:22");
+        assert_crossing(london.nautical_twilight, "2024-06-21T02:41", "2024-06-21T23:24");
+        assert_eq!(london.astronomical_twilight, Crossing::AlwaysAbove);
+
+        // New York, winter solstice, EST
+        let new_york = daylight(coordinates(40.71, -74.), date("2024-12-21"), -300);
+        assert_crossing(new_york.sunrise_and_sunset, "2024-12-21T07:17", "2024-12-21T16:32");
+        assert_crossing(new_york.civil_twilight, "2024-12-21T06:46", "2024-12-21T17:03");
+        assert_crossing(new_york.astronomical_twilight, "2024-12-21T05:38", "2024-12-21T18:11");
+
+        // Sydney, summer solstice, AEDT
+        let sydney = daylight(coordinates(-33.86, 151.2), date("2024-12-21"), 660);
+        assert_time(sydney.solar_noon, "2024-12-21T12:53");
+        assert_crossing(sydney.sunrise_and_sunset, "2024-12-21T05:41", "2024-12-21T20:06");
+
+        // Null Island, March equinox, UTC
+        let null_island = daylight(coordinates(0., 0.), date("2024-03-20"), 0);
+        assert_time(null_island.solar_noon, "2024-03-20T12:07");
+        assert_crossing(null_island.sunrise_and_sunset, "2024-03-20T06:04", "2024-03-20T18:11");
+    }
+
+    #[test]
+    fn polar_day_and_night() {
+        let tromso = coordinates(69.65, 18.95);
+
+        let midsummer = daylight(tromso, date("2024-06-21"), 120);
+        assert_eq!(midsummer.sunrise_and_sunset, Crossing::AlwaysAbove);
+        assert_eq!(midsummer.sunrise_and_sunset.minutes_above(), 1440);
+        assert_time(midsummer.solar_noon, "2024-06-21T12:46");
+
+        let midwinter = daylight(tromso, date("2024-12-21"), 60);
+        assert_eq!(midwinter.sunrise_and_sunset, Crossing::AlwaysBelow);
+        assert_eq!(midwinter.sunrise_and_sunset.minutes_above(), 0);
+        assert_crossing(midwinter.civil_twilight, "2024-12-21T09:32", "2024-12-21T13:53");
+
+        // Even civil twilight fails to occur further north
+        let longyearbyen = daylight(coordinates(78.22, 15.65), date("2024-12-21"), 60);
+        assert_eq!(longyearbyen.civil_twilight, Crossing::AlwaysBelow);
+        assert_crossing(longyearbyen.nautical_twilight, "2024-12-21T10:58", "2024-12-21T12:53");
+    }
+
+    #[test]
+    fn solar_positions() {
+        let table = [
+            (39.742476, -105.1786, "2003-10-17T19:30", 39.92, 194.18),
+            (40., -105., "2010-06-21T18:00", 68.93, 137.17),
+            (-33.86, 151.2, "2024-12-21T00:00", 63.17, 74.62),
+        ];
+
+        for (latitude, longitude, time, elevation, azimuth) in table {
+            let position = solar_position(coordinates(latitude, longitude), time.parse().unwrap());
+            assert!((position.elevation - elevation).abs() <= 0.05, "{} != {elevation}", position.elevation);
+            assert!((position.azimuth - azimuth).abs() <= 0.05, "{} != {azimuth}", position.azimuth);
+        }
+    }
+}
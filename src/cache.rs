@@ -0,0 +1,335 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::data::{coordinates, Coordinates};
+use crate::provider::{ForecastDays, WeatherData};
+
+/// Shared by the keys of every version of the cache.
+const STORAGE_NAMESPACE: &str = "stormy_skies.weather.";
+
+/// Bumped whenever the key format or the stored data changes, entries of older versions are removed.
+const STORAGE_KEY_PREFIX: &str = "stormy_skies.weather.v2.";
+
+/// Stored entries older than this are removed, as they are no longer worth showing even while offline.
+const STORAGE_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Time after a failed revalidation before the next one is attempted.
+pub const REVALIDATION_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Coordinate units per cell of 0.01°.
+const UNITS_PER_CELL: i64 = 10_i64.pow(coordinates::DECIMALS - 2);
+
+/// Coordinates are grouped into cells of 0.01° (roughly 1 km), which is finer than the
+/// grid of any of the weather models used.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CacheKey(String);
+
+impl CacheKey {
+    pub fn new(provider_name: &str, coordinates: Coordinates, forecast_days: ForecastDays) -> Self {
+        // Integer division of the exact units, so that points on both sides of a cell border are never mixed up
+        CacheKey(format!(
+            "{}:{}:{}:{}",
+            provider_name,
+            coordinates.latitude.units().div_euclid(UNITS_PER_CELL),
+            coordinates.longitude.units().div_euclid(UNITS_PER_CELL),
+            u8::from(forecast_days),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Entry {
+    fetched_at_millis: f64,
+    weather_data: WeatherData,
+}
+
+/// Reads only the age of a stored entry, without deserializing its weather data.
+#[derive(Deserialize)]
+struct StoredAge {
+    fetched_at_millis: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct CachedWeatherData {
+    pub weather_data: WeatherData,
+    pub fetched_at_millis: f64,
+    pub is_stale: bool,
+    /// The last revalidation failed, another one is attempted after [`REVALIDATION_RETRY_DELAY`].
+    pub refresh_failed: bool,
+}
+
+/// Cache for weather data, held in memory and optionally mirrored into the `localStorage` of the browser.
+/// Expired entries are still served, but flagged as stale.
+/// All points in time are given in milliseconds since the Unix epoch.
+#[derive(Debug)]
+pub struct WeatherCache {
+    time_to_live: Duration,
+    entries: RefCell<HashMap<CacheKey, Entry>>,
+    revalidating: RefCell<HashSet<CacheKey>>,
+    failed_revalidations: RefCell<HashMap<CacheKey, f64>>,
+    storage: Option<web_sys::Storage>,
+}
+
+impl WeatherCache {
+    pub fn in_memory(time_to_live: Duration) -> Self {
+        WeatherCache {
+            time_to_live,
+            entries: RefCell::default(),
+            revalidating: RefCell::default(),
+            failed_revalidations: RefCell::default(),
+            storage: None,
+        }
+    }
+
+    /// Falls back to a cache held in memory only if the browser does not grant access to `localStorage`.
+    /// Entries stored by older versions of the cache are removed.
+    pub fn with_local_storage(time_to_live: Duration) -> Self {
+        let storage = web_sys::window().and_then(|window| window.local_storage().ok().flatten());
+
+        if let Some(storage) = storage.as_ref() {
+            for storage_key in stored_keys(storage) {
+                if !storage_key.starts_with(STORAGE_KEY_PREFIX) {
+                    let _ = storage.remove_item(&storage_key);
+                }
+            }
+        }
+
+        WeatherCache { storage, ..WeatherCache::in_memory(time_to_live) }
+    }
+
+    pub fn get(&self, key: &CacheKey, now_millis: f64) -> Option<CachedWeatherData> {
+        let entry = self.entries.borrow().get(key).cloned().or_else(|| {
+            let entry = self.load(key)?;
+            self.entries.borrow_mut().insert(key.clone(), entry.clone());
+            Some(entry)
+        })?;
+
+        let age_millis = now_millis - entry.fetched_at_millis;
+
+        Some(CachedWeatherData {
+            weather_data: entry.weather_data,
+            fetched_at_millis: entry.fetched_at_millis,
+            is_stale: age_millis > self.time_to_live.as_millis() as f64,
+            refresh_failed: self.failed_revalidations.borrow().contains_key(key),
+        })
+    }
+
+    pub fn insert(&self, key: CacheKey, weather_data: WeatherData, now_millis: f64) {
+        let entry = Entry { fetched_at_millis: now_millis, weather_data };
+        self.store(&key, &entry, now_millis);
+        self.failed_revalidations.borrow_mut().remove(&key);
+        self.entries.borrow_mut().insert(key, entry);
+    }
+
+    /// Marks the key as being revalidated. Returns false if a revalidation is already in progress,
+    /// or if the last one failed less than [`REVALIDATION_RETRY_DELAY`] ago.
+    pub fn begin_revalidation(&self, key: &CacheKey, now_millis: f64) -> bool {
+        let retry_pending = self.failed_revalidations.borrow().get(key).is_some_and(|failed_at_millis| {
+            now_millis - failed_at_millis < REVALIDATION_RETRY_DELAY.as_millis() as f64
+        });
+
+        !retry_pending && self.revalidating.borrow_mut().insert(key.clone())
+    }
+
+    pub fn end_revalidation(&self, key: &CacheKey) {
+        self.revalidating.borrow_mut().remove(key);
+    }
+
+    pub fn fail_revalidation(&self, key: &CacheKey, now_millis: f64) {
+        self.failed_revalidations.borrow_mut().insert(key.clone(), now_millis);
+        self.end_revalidation(key);
+    }
+
+    fn load(&self, key: &CacheKey) -> Option<Entry> {
+        let storage = self.storage.as_ref()?;
+        let storage_key = storage_key(key);
+        let json = storage.get_item(&storage_key).ok()??;
+
+        // An entry that cannot be read now never will be, so it only takes up space
+        let entry = serde_json::from_str(&json).ok();
+        if entry.is_none() {
+            let _ = storage.remove_item(&storage_key);
+        }
+        entry
+    }
+
+    fn store(&self, key: &CacheKey, entry: &Entry, now_millis: f64) {
+        let Some(storage) = self.storage.as_ref() else {
+            return;
+        };
+        let Ok(json) = serde_json::to_string(entry) else {
+            return;
+        };
+        let storage_key = storage_key(key);
+
+        let stored = stored_keys(storage).into_iter()
+            .filter(|stored_key| *stored_key != storage_key)
+            .map(|stored_key| {
+                let fetched_at_millis = storage.get_item(&stored_key).ok().flatten()
+                    .and_then(|json| serde_json::from_str::<StoredAge>(&json).ok())
+                    .map(|age| age.fetched_at_millis);
+                (stored_key, fetched_at_millis)
+            })
+            .collect();
+
+        let (expired, oldest_first) = plan_eviction(stored, now_millis);
+
+        for stored_key in expired {
+            let _ = storage.remove_item(&stored_key);
+        }
+
+        // A full storage makes room by dropping the oldest entries. Only once none are left is persistence given up,
+        // the entry is still held in memory then.
+        let mut oldest_first = oldest_first.into_iter();
+        while storage.set_item(&storage_key, &json).is_err() {
+            let Some(stored_key) = oldest_first.next() else {
+                return;
+            };
+            let _ = storage.remove_item(&stored_key);
+        }
+    }
+}
+
+fn storage_key(key: &CacheKey) -> String {
+    format!("{}{}", STORAGE_KEY_PREFIX, key.0)
+}
+
+/// All keys in the storage belonging to any version of the cache.
+fn stored_keys(storage: &web_sys::Storage) -> Vec<String> {
+    (0..storage.length().unwrap_or(0))
+        .filter_map(|index| storage.key(index).ok().flatten())
+        .filter(|storage_key| storage_key.starts_with(STORAGE_NAMESPACE))
+        .collect()
+}
+
+/// Splits stored entries, given with the time they were fetched at if it can be read, into those to remove right away
+/// because they are unreadable or older than [`STORAGE_MAX_AGE`], and the remaining ones from oldest to newest.
+fn plan_eviction(stored: Vec<(String, Option<f64>)>, now_millis: f64) -> (Vec<String>, Vec<String>) {
+    let max_age_millis = STORAGE_MAX_AGE.as_millis() as f64;
+    let mut expired = Vec::new();
+    let mut remaining = Vec::new();
+
+    for (storage_key, fetched_at_millis) in stored {
+        match fetched_at_millis {
+            Some(fetched_at_millis) if now_millis - fetched_at_millis <= max_age_millis => {
+                remaining.push((fetched_at_millis, storage_key));
+            }
+            _ => expired.push(storage_key),
+        }
+    }
+
+    remaining.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    (expired, remaining.into_iter().map(|(_, storage_key)| storage_key).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::cache::{plan_eviction, CacheKey, WeatherCache, REVALIDATION_RETRY_DELAY, STORAGE_MAX_AGE};
+    use crate::data::{Percentage, Temperature};
+    use crate::provider::{ForecastDays, WeatherData};
+    use crate::test_util::{coordinates, weather_data};
+
+    #[test]
+    fn keys() {
+        let key = |provider, latitude, longitude, days| {
+            CacheKey::new(provider, coordinates(latitude, longitude), ForecastDays::try_from(days).unwrap())
+        };
+
+        assert_eq!(key("A", 52.5, 13.25, 7), key("A", 52.5, 13.25, 7));
+        assert_ne!(key("A", 52.5, 13.25, 7), key("B", 52.5, 13.25, 7));
+        assert_ne!(key("A", 52.5, 13.25, 7), key("A", 52.5, 13.25, 8));
+        assert_ne!(key("A", 52.5, 13.25, 7), key("A", 52.5, 13.5, 7));
+    }
+
+    #[test]
+    fn key_cells() {
+        let key = |latitude, longitude| CacheKey::new("A", coordinates(latitude, longitude), ForecastDays::default());
+
+        assert_eq!(key(52.52, 13.41), key(52.529999, 13.419999));
+        assert_eq!(key(-33.86, -151.21), key(-33.851, -151.209999));
+        assert_ne!(key(52.52, 13.41), key(52.519999, 13.41));
+        assert_ne!(key(0.005, 0.), key(-0.005, 0.));
+    }
+
+    #[test]
+    fn fresh_and_stale_entries() {
+        let cache = WeatherCache::in_memory(Duration::from_secs(60));
+        let key = CacheKey::new("A", coordinates(52.5, 13.25), ForecastDays::default());
+
+        assert!(cache.get(&key, 0.).is_none());
+
+        cache.insert(key.clone(), weather_data(20.), 1_000.);
+
+        let cached = cache.get(&key, 61_000.).unwrap();
+        assert!(!cached.is_stale);
+        assert_eq!(cached.weather_data.current_temperature, Temperature::Celsius(20.));
+
+        let cached = cache.get(&key, 61_001.).unwrap();
+        assert!(cached.is_stale);
+
+        cache.insert(key.clone(), weather_data(21.), 61_001.);
+        let cached = cache.get(&key, 61_002.).unwrap();
+        assert!(!cached.is_stale);
+        assert_eq!(cached.weather_data.current_temperature, Temperature::Celsius(21.));
+    }
+
+    #[test]
+    fn single_revalidation_per_key() {
+        let cache = WeatherCache::in_memory(Duration::from_secs(60));
+        let key = CacheKey::new("A", coordinates(52.5, 13.25), ForecastDays::default());
+
+        assert!(cache.begin_revalidation(&key, 0.));
+        assert!(!cache.begin_revalidation(&key, 0.));
+        cache.end_revalidation(&key);
+        assert!(cache.begin_revalidation(&key, 0.));
+    }
+
+    #[test]
+    fn failed_revalidation() {
+        let cache = WeatherCache::in_memory(Duration::from_secs(60));
+        let key = CacheKey::new("A", coordinates(52.5, 13.25), ForecastDays::default());
+        let retry_delay_millis = REVALIDATION_RETRY_DELAY.as_millis() as f64;
+
+        cache.insert(key.clone(), weather_data(20.), 0.);
+        assert!(cache.begin_revalidation(&key, 61_000.));
+        cache.fail_revalidation(&key, 62_000.);
+
+        let cached = cache.get(&key, 62_000.).unwrap();
+        assert!(cached.is_stale);
+        assert!(cached.refresh_failed);
+
+        assert!(!cache.begin_revalidation(&key, 62_000. + retry_delay_millis - 1.));
+        assert!(cache.begin_revalidation(&key, 62_000. + retry_delay_millis));
+
+        cache.insert(key.clone(), weather_data(21.), 63_000.);
+        cache.end_revalidation(&key);
+        assert!(!cache.get(&key, 63_000.).unwrap().refresh_failed);
+    }
+
+    #[test]
+    fn eviction() {
+        let now_millis = 1_000_000_000.;
+        let max_age_millis = STORAGE_MAX_AGE.as_millis() as f64;
+        let stored = vec![
+            ("new".to_string(), Some(now_millis - 1_000.)),
+            ("unreadable".to_string(), None),
+            ("old".to_string(), Some(now_millis - max_age_millis)),
+            ("expired".to_string(), Some(now_millis - max_age_millis - 1.)),
+            ("older".to_string(), Some(now_millis - 2_000.)),
+        ];
+
+        let (expired, oldest_first) = plan_eviction(stored, now_millis);
+        assert_eq!(expired, ["unreadable", "expired"]);
+        assert_eq!(oldest_first, ["old", "older", "new"]);
+    }
+
+    #[test]
+    fn entries_survive_serialization() {
+        let json = serde_json::to_string(&weather_data(20.)).unwrap();
+        let weather_data = serde_json::from_str::<WeatherData>(&json).unwrap();
+        assert_eq!(weather_data.current_temperature, Temperature::Celsius(20.));
+        assert_eq!(weather_data.current_humidity, Percentage::try_from(50).unwrap());
+    }
+}
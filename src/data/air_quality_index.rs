@@ -0,0 +1,160 @@
+use std::fmt::{self, Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::data::Measurement;
+use crate::i18n::Locale;
+
+/// Dimensionless index summarizing the concentrations of several pollutants.
+/// The scales differ, so values of the European and the US index are not comparable.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum AirQualityIndex {
+    European(f32),
+    UnitedStates(f32),
+}
+
+impl AirQualityIndex {
+    /// See https://airindex.eea.europa.eu/ and https://www.airnow.gov/aqi/aqi-basics/.
+    pub fn health_band(&self) -> HealthBand {
+        match *self {
+            AirQualityIndex::European(value) => HealthBand::European(match value {
+                value if value <= 20. => EuropeanHealthBand::Good,
+                value if value <= 40. => EuropeanHealthBand::Fair,
+                value if value <= 60. => EuropeanHealthBand::Moderate,
+                value if value <= 80. => EuropeanHealthBand::Poor,
+                value if value <= 100. => EuropeanHealthBand::VeryPoor,
+                _ => EuropeanHealthBand::ExtremelyPoor,
+            }),
+
+            AirQualityIndex::UnitedStates(value) => HealthBand::UnitedStates(match value {
+                value if value <= 50. => UnitedStatesHealthBand::Good,
+                value if value <= 100. => UnitedStatesHealthBand::Moderate,
+                value if value <= 150. => UnitedStatesHealthBand::UnhealthyForSensitiveGroups,
+                value if value <= 200. => UnitedStatesHealthBand::Unhealthy,
+                value if value <= 300. => UnitedStatesHealthBand::VeryUnhealthy,
+                _ => UnitedStatesHealthBand::Hazardous,
+            }),
+        }
+    }
+}
+
+impl Display for AirQualityIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&f32::from(*self), f)
+    }
+}
+
+impl From<AirQualityIndex> for f32 {
+    fn from(value: AirQualityIndex) -> Self {
+        match value {
+            AirQualityIndex::European(value) => value,
+            AirQualityIndex::UnitedStates(value) => value,
+        }
+    }
+}
+
+impl Measurement for AirQualityIndex {
+    fn unit(&self) -> &'static str {
+        ""
+    }
+
+    fn decimal_places(&self) -> usize {
+        0
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum EuropeanHealthBand {
+    Good,
+    Fair,
+    Moderate,
+    Poor,
+    VeryPoor,
+    ExtremelyPoor,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum UnitedStatesHealthBand {
+    Good,
+    Moderate,
+    UnhealthyForSensitiveGroups,
+    Unhealthy,
+    VeryUnhealthy,
+    Hazardous,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HealthBand {
+    European(EuropeanHealthBand),
+    UnitedStates(UnitedStatesHealthBand),
+}
+
+impl HealthBand {
+    /// Position of the band within its scale, from 0 for the best to 5 for the worst air quality.
+    pub fn severity(&self) -> u8 {
+        match *self {
+            HealthBand::European(band) => band as u8,
+            HealthBand::UnitedStates(band) => band as u8,
+        }
+    }
+
+    pub fn name(&self, locale: Locale) -> &'static str {
+        let (english, german, french) = match self {
+            HealthBand::European(EuropeanHealthBand::Good) => ("Good", "Gut", "Bon"),
+            HealthBand::European(EuropeanHealthBand::Fair) => ("Fair", "Akzeptabel", "Correct"),
+            HealthBand::European(EuropeanHealthBand::Moderate) => ("Moderate", "Mäßig", "Moyen"),
+            HealthBand::European(EuropeanHealthBand::Poor) => ("Poor", "Schlecht", "Mauvais"),
+            HealthBand::European(EuropeanHealthBand::VeryPoor) => ("Very poor", "Sehr schlecht", "Très mauvais"),
+            HealthBand::European(EuropeanHealthBand::ExtremelyPoor) => ("Extremely poor", "Extrem schlecht", "Extrêmement mauvais"),
+            HealthBand::UnitedStates(UnitedStatesHealthBand::Good) => ("Good", "Gut", "Bon"),
+            HealthBand::UnitedStates(UnitedStatesHealthBand::Moderate) => ("Moderate", "Mäßig", "Modéré"),
+            HealthBand::UnitedStates(UnitedStatesHealthBand::UnhealthyForSensitiveGroups) => (
+                "Unhealthy for sensitive groups",
+                "Ungesund für empfindliche Gruppen",
+                "Mauvais pour les groupes sensibles",
+            ),
+            HealthBand::UnitedStates(UnitedStatesHealthBand::Unhealthy) => ("Unhealthy", "Ungesund", "Mauvais"),
+            HealthBand::UnitedStates(UnitedStatesHealthBand::VeryUnhealthy) => ("Very unhealthy", "Sehr ungesund", "Très mauvais"),
+            HealthBand::UnitedStates(UnitedStatesHealthBand::Hazardous) => ("Hazardous", "Gefährlich", "Dangereux"),
+        };
+
+        locale.select(english, german, french)
+    }
+}
+
+impl Display for HealthBand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::air_quality_index::{AirQualityIndex, EuropeanHealthBand, HealthBand, UnitedStatesHealthBand};
+
+    #[test]
+    fn european_health_bands() {
+        let band = |value| AirQualityIndex::European(value).health_band();
+
+        assert_eq!(band(0.), HealthBand::European(EuropeanHealthBand::Good));
+        assert_eq!(band(20.), HealthBand::European(EuropeanHealthBand::Good));
+        assert_eq!(band(20.5), HealthBand::European(EuropeanHealthBand::Fair));
+        assert_eq!(band(55.), HealthBand::European(EuropeanHealthBand::Moderate));
+        assert_eq!(band(80.), HealthBand::European(EuropeanHealthBand::Poor));
+        assert_eq!(band(100.), HealthBand::European(EuropeanHealthBand::VeryPoor));
+        assert_eq!(band(140.), HealthBand::European(EuropeanHealthBand::ExtremelyPoor));
+        assert_eq!(band(140.).severity(), 5);
+    }
+
+    #[test]
+    fn united_states_health_bands() {
+        let band = |value| AirQualityIndex::UnitedStates(value).health_band();
+
+        assert_eq!(band(50.), HealthBand::UnitedStates(UnitedStatesHealthBand::Good));
+        assert_eq!(band(51.), HealthBand::UnitedStates(UnitedStatesHealthBand::Moderate));
+        assert_eq!(band(101.), HealthBand::UnitedStates(UnitedStatesHealthBand::UnhealthyForSensitiveGroups));
+        assert_eq!(band(200.), HealthBand::UnitedStates(UnitedStatesHealthBand::Unhealthy));
+        assert_eq!(band(300.), HealthBand::UnitedStates(UnitedStatesHealthBand::VeryUnhealthy));
+        assert_eq!(band(301.), HealthBand::UnitedStates(UnitedStatesHealthBand::Hazardous));
+        assert_eq!(band(101.).to_string(), "Unhealthy for sensitive groups");
+        assert_eq!(band(101.).severity(), 2);
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::{self, Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::data::{CompassDirection, CompassResolution, Measurement};
+
+/// Direction in degrees clockwise from true north, normalised into [0, 360).
+/// Wind and wave directions give the direction they are coming from.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(try_from = "f32", into = "f32")]
+pub struct Bearing(f32);
+
+impl Bearing {
+    pub fn compass_direction(&self, resolution: CompassResolution) -> CompassDirection {
+        CompassDirection::from_degrees_with_resolution(self.0, resolution).expect("Bearings are always finite")
+    }
+
+    /// The bearing pointing the other way, e.g. where the wind is blowing to.
+    pub fn opposite(&self) -> Bearing {
+        Bearing::try_from(self.0 + 180.).expect("Bearings are always finite")
+    }
+}
+
+impl TryFrom<f32> for Bearing {
+    type Error = Error;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(Error::InvalidDegrees(value));
+        }
+
+        // Rounding may produce 360 for values just below zero
+        let degrees = value.rem_euclid(360.);
+        Ok(Bearing(if degrees == 360. { 0. } else { degrees }))
+    }
+}
+
+impl From<Bearing> for f32 {
+    fn from(value: Bearing) -> Self {
+        value.0
+    }
+}
+
+impl Display for Bearing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)?;
+        write!(f, "{}", self.unit())
+    }
+}
+
+impl Measurement for Bearing {
+    fn unit(&self) -> &'static str {
+        "°"
+    }
+
+    fn decimal_places(&self) -> usize {
+        0
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The angle of {0}° cannot be converted to a Bearing")]
+    InvalidDegrees(f32),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{Bearing, CompassDirection, CompassResolution};
+
+    fn degrees(bearing: Bearing) -> f32 {
+        f32::from(bearing)
+    }
+
+    #[test]
+    fn normalisation() {
+        assert_eq!(degrees(Bearing::try_from(0.).unwrap()), 0.);
+        assert_eq!(degrees(Bearing::try_from(248.5).unwrap()), 248.5);
+        assert_eq!(degrees(Bearing::try_from(360.).unwrap()), 0.);
+        assert_eq!(degrees(Bearing::try_from(-90.).unwrap()), 270.);
+        assert_eq!(degrees(Bearing::try_from(-1e-6).unwrap()), 0.);
+        assert_eq!(degrees(Bearing::try_from(725.).unwrap()), 5.);
+    }
+
+    #[test]
+    fn from_invalid_degrees() {
+        assert!(Bearing::try_from(f32::NAN).is_err());
+        assert!(Bearing::try_from(f32::INFINITY).is_err());
+        assert!(Bearing::try_from(f32::NEG_INFINITY).is_err());
+    }
+
+    #[test]
+    fn compass_directions() {
+        let bearing = Bearing::try_from(256.).unwrap();
+
+        assert_eq!(bearing.compass_direction(CompassResolution::EightPoints), CompassDirection::W);
+        assert_eq!(bearing.compass_direction(CompassResolution::SixteenPoints), CompassDirection::WSW);
+        assert_eq!(bearing.compass_direction(CompassResolution::ThirtyTwoPoints), CompassDirection::WbS);
+        assert_eq!(degrees(bearing.opposite()), 76.);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{:.0}", Bearing::try_from(248.4).unwrap()), "248°");
+        assert_eq!(Bearing::try_from(-22.5).unwrap().to_string(), "337.5°");
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let json = serde_json::to_string(&Bearing::try_from(248.).unwrap()).unwrap();
+        assert_eq!(serde_json::from_str::<Bearing>(&json).unwrap(), Bearing::try_from(248.).unwrap());
+        assert!(serde_json::from_str::<Bearing>("\"SW\"").is_err());
+    }
+}
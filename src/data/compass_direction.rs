@@ -1,55 +1,188 @@
 use std::fmt::{self, Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::i18n::Locale;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+const POINTS: usize = 32;
+
+/// Number of points of the compass rose that angles are rounded to.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CompassResolution {
+    #[default]
+    EightPoints,
+    SixteenPoints,
+    ThirtyTwoPoints,
+}
+
+impl CompassResolution {
+    pub fn points(self) -> usize {
+        match self {
+            CompassResolution::EightPoints => 8,
+            CompassResolution::SixteenPoints => 16,
+            CompassResolution::ThirtyTwoPoints => POINTS,
+        }
+    }
+}
+
+/// The 32 points of the compass rose in clockwise order, `b` standing for "by".
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum CompassDirection {
     N,
+    NbE,
+    NNE,
+    NEbN,
     NE,
+    NEbE,
+    ENE,
+    EbN,
     E,
+    EbS,
+    ESE,
+    SEbE,
     SE,
+    SEbS,
+    SSE,
+    SbE,
     S,
+    SbW,
+    SSW,
+    SWbS,
     SW,
+    SWbW,
+    WSW,
+    WbS,
     W,
+    WbN,
+    WNW,
+    NWbW,
     NW,
+    NWbN,
+    NNW,
+    NbW,
 }
 
 impl CompassDirection {
+    pub const ALL: [CompassDirection; POINTS] = [
+        CompassDirection::N, CompassDirection::NbE, CompassDirection::NNE, CompassDirection::NEbN,
+        CompassDirection::NE, CompassDirection::NEbE, CompassDirection::ENE, CompassDirection::EbN,
+        CompassDirection::E, CompassDirection::EbS, CompassDirection::ESE, CompassDirection::SEbE,
+        CompassDirection::SE, CompassDirection::SEbS, CompassDirection::SSE, CompassDirection::SbE,
+        CompassDirection::S, CompassDirection::SbW, CompassDirection::SSW, CompassDirection::SWbS,
+        CompassDirection::SW, CompassDirection::SWbW, CompassDirection::WSW, CompassDirection::WbS,
+        CompassDirection::W, CompassDirection::WbN, CompassDirection::WNW, CompassDirection::NWbW,
+        CompassDirection::NW, CompassDirection::NWbN, CompassDirection::NNW, CompassDirection::NbW,
+    ];
+
+    /// Rounds to one of the eight principal winds.
     pub fn from_degrees(degrees: f32) -> Result<Self, Error> {
+        CompassDirection::from_degrees_with_resolution(degrees, CompassResolution::EightPoints)
+    }
+
+    /// Rounds to the nearest point of the compass rose with the given resolution.
+    pub fn from_degrees_with_resolution(degrees: f32, resolution: CompassResolution) -> Result<Self, Error> {
         if !degrees.is_finite() {
             return Err(Error::InvalidDegrees(degrees));
         }
 
-        let id = (((((degrees + 22.5) % 360.) + 360.) % 360.) as u32) / 45;
-
-        let compass_direction = match id {
-            0 => CompassDirection::N,
-            1 => CompassDirection::NE,
-            2 => CompassDirection::E,
-            3 => CompassDirection::SE,
-            4 => CompassDirection::S,
-            5 => CompassDirection::SW,
-            6 => CompassDirection::W,
-            7 => CompassDirection::NW,
-            _ => panic!("This should not be reachable")
+        let points = resolution.points();
+        let sector = 360. / points as f32;
+        let id = (((((degrees + sector / 2.) % 360.) + 360.) % 360.) / sector) as usize;
+
+        // Rounding errors may yield a full turn for angles just below north
+        Ok(CompassDirection::ALL[id % points * (POINTS / points)])
+    }
+
+    /// Bearing in the middle of the sector of the point, in degrees clockwise from north.
+    pub fn central_bearing(&self) -> f32 {
+        *self as usize as f32 * 360. / POINTS as f32
+    }
+
+    pub fn abbreviation(&self, locale: Locale) -> &'static str {
+        let (english, german, french) = match self {
+            CompassDirection::N => ("N", "N", "N"),
+            CompassDirection::NbE => ("NbE", "NzO", "N¼NE"),
+            CompassDirection::NNE => ("NNE", "NNO", "NNE"),
+            CompassDirection::NEbN => ("NEbN", "NOzN", "NE¼N"),
+            CompassDirection::NE => ("NE", "NO", "NE"),
+            CompassDirection::NEbE => ("NEbE", "NOzO", "NE¼E"),
+            CompassDirection::ENE => ("ENE", "ONO", "ENE"),
+            CompassDirection::EbN => ("EbN", "OzN", "E¼NE"),
+            CompassDirection::E => ("E", "O", "E"),
+            CompassDirection::EbS => ("EbS", "OzS", "E¼SE"),
+            CompassDirection::ESE => ("ESE", "OSO", "ESE"),
+            CompassDirection::SEbE => ("SEbE", "SOzO", "SE¼E"),
+            CompassDirection::SE => ("SE", "SO", "SE"),
+            CompassDirection::SEbS => ("SEbS", "SOzS", "SE¼S"),
+            CompassDirection::SSE => ("SSE", "SSO", "SSE"),
+            CompassDirection::SbE => ("SbE", "SzO", "S¼SE"),
+            CompassDirection::S => ("S", "S", "S"),
+            CompassDirection::SbW => ("SbW", "SzW", "S¼SO"),
+            CompassDirection::SSW => ("SSW", "SSW", "SSO"),
+            CompassDirection::SWbS => ("SWbS", "SWzS", "SO¼S"),
+            CompassDirection::SW => ("SW", "SW", "SO"),
+            CompassDirection::SWbW => ("SWbW", "SWzW", "SO¼O"),
+            CompassDirection::WSW => ("WSW", "WSW", "OSO"),
+            CompassDirection::WbS => ("WbS", "WzS", "O¼SO"),
+            CompassDirection::W => ("W", "W", "O"),
+            CompassDirection::WbN => ("WbN", "WzN", "O¼NO"),
+            CompassDirection::WNW => ("WNW", "WNW", "ONO"),
+            CompassDirection::NWbW => ("NWbW", "NWzW", "NO¼O"),
+            CompassDirection::NW => ("NW", "NW", "NO"),
+            CompassDirection::NWbN => ("NWbN", "NWzN", "NO¼N"),
+            CompassDirection::NNW => ("NNW", "NNW", "NNO"),
+            CompassDirection::NbW => ("NbW", "NzW", "N¼NO"),
+        };
+
+        locale.select(english, german, french)
+    }
+
+    pub fn name(&self, locale: Locale) -> &'static str {
+        let (english, german, french) = match self {
+            CompassDirection::N => ("North", "Nord", "Nord"),
+            CompassDirection::NbE => ("North by east", "Nord zu Ost", "Nord quart nord-est"),
+            CompassDirection::NNE => ("North-northeast", "Nordnordost", "Nord-nord-est"),
+            CompassDirection::NEbN => ("Northeast by north", "Nordost zu Nord", "Nord-est quart nord"),
+            CompassDirection::NE => ("Northeast", "Nordost", "Nord-est"),
+            CompassDirection::NEbE => ("Northeast by east", "Nordost zu Ost", "Nord-est quart est"),
+            CompassDirection::ENE => ("East-northeast", "Ostnordost", "Est-nord-est"),
+            CompassDirection::EbN => ("East by north", "Ost zu Nord", "Est quart nord-est"),
+            CompassDirection::E => ("East", "Ost", "Est"),
+            CompassDirection::EbS => ("East by south", "Ost zu Süd", "Est quart sud-est"),
+            CompassDirection::ESE => ("East-southeast", "Ostsüdost", "Est-sud-est"),
+            CompassDirection::SEbE => ("Southeast by east", "Südost zu Ost", "Sud-est quart est"),
+            CompassDirection::SE => ("Southeast", "Südost", "Sud-est"),
+            CompassDirection::SEbS => ("Southeast by south", "Südost zu Süd", "Sud-est quart sud"),
+            CompassDirection::SSE => ("South-southeast", "Südsüdost", "Sud-sud-est"),
+            CompassDirection::SbE => ("South by east", "Süd zu Ost", "Sud quart sud-est"),
+            CompassDirection::S => ("South", "Süd", "Sud"),
+            CompassDirection::SbW => ("South by west", "Süd zu West", "Sud quart sud-ouest"),
+            CompassDirection::SSW => ("South-southwest", "Südsüdwest", "Sud-sud-ouest"),
+            CompassDirection::SWbS => ("Southwest by south", "Südwest zu Süd", "Sud-ouest quart sud"),
+            CompassDirection::SW => ("Southwest", "Südwest", "Sud-ouest"),
+            CompassDirection::SWbW => ("Southwest by west", "Südwest zu West", "Sud-ouest quart ouest"),
+            CompassDirection::WSW => ("West-southwest", "Westsüdwest", "Ouest-sud-ouest"),
+            CompassDirection::WbS => ("West by south", "West zu Süd", "Ouest quart sud-ouest"),
+            CompassDirection::W => ("West", "West", "Ouest"),
+            CompassDirection::WbN => ("West by north", "West zu Nord", "Ouest quart nord-ouest"),
+            CompassDirection::WNW => ("West-northwest", "Westnordwest", "Ouest-nord-ouest"),
+            CompassDirection::NWbW => ("Northwest by west", "Nordwest zu West", "Nord-ouest quart ouest"),
+            CompassDirection::NW => ("Northwest", "Nordwest", "Nord-ouest"),
+            CompassDirection::NWbN => ("Northwest by north", "Nordwest zu Nord", "Nord-ouest quart nord"),
+            CompassDirection::NNW => ("North-northwest", "Nordnordwest", "Nord-nord-ouest"),
+            CompassDirection::NbW => ("North by west", "Nord zu West", "Nord quart nord-ouest"),
         };
 
-        Ok(compass_direction)
+        locale.select(english, german, french)
     }
 }
 
+/// The abbreviation in English, or the full name with the alternate flag, e.g. `{:#}`.
 impl Display for CompassDirection {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let string = match self {
-            CompassDirection::N => "N",
-            CompassDirection::NE => "NE",
-            CompassDirection::E => "E",
-            CompassDirection::SE => "SE",
-            CompassDirection::S => "S",
-            CompassDirection::SW => "SW",
-            CompassDirection::W => "W",
-            CompassDirection::NW => "NW",
-        };
-
-        write!(f, "{}", string)
+        if f.alternate() {
+            write!(f, "{}", self.name(Locale::English))
+        } else {
+            write!(f, "{}", self.abbreviation(Locale::English))
+        }
     }
 }
 
@@ -61,7 +194,8 @@ pub enum Error {
 
 #[cfg(test)]
 mod tests {
-    use crate::data::CompassDirection;
+    use crate::data::{CompassDirection, CompassResolution};
+    use crate::i18n::Locale;
 
     fn degrees_range(from_inclusive: f32, to_exclusive: f32) -> impl Iterator<Item=f32> {
         let from = (100. * from_inclusive) as i32;
@@ -70,6 +204,14 @@ mod tests {
         (from..to).map(|degrees| (degrees as f32) / 100.)
     }
 
+    /// Thousandths of a degree, as the sectors of the finer resolutions start at e.g. 5.625°.
+    fn fine_degrees_range(from_inclusive: f32, to_exclusive: f32) -> impl Iterator<Item=f32> {
+        let from = (1000. * from_inclusive) as i32;
+        let to = (1000. * to_exclusive) as i32;
+
+        (from..to).map(|degrees| (degrees as f32) / 1000.)
+    }
+
     fn from_degrees_test(start_degrees: f32, expected: CompassDirection) {
         let end_degrees = start_degrees + 45.;
 
@@ -86,6 +228,16 @@ mod tests {
         }
     }
 
+    fn from_degrees_with_resolution_test(start_degrees: f32, resolution: CompassResolution, expected: CompassDirection) {
+        let end_degrees = start_degrees + 360. / resolution.points() as f32;
+
+        for offset in [-360., 0., 360.] {
+            for d in fine_degrees_range(start_degrees + offset, end_degrees + offset) {
+                assert_eq!(CompassDirection::from_degrees_with_resolution(d, resolution).unwrap(), expected, "{d}°");
+            }
+        }
+    }
+
     #[test]
     fn from_degrees_north() {
         from_degrees_test(-22.5, CompassDirection::N);
@@ -126,6 +278,284 @@ mod tests {
         from_degrees_test(292.5, CompassDirection::NW);
     }
 
+    #[test]
+    fn from_degrees_sixteen_points_north() {
+        from_degrees_with_resolution_test(-11.25, CompassResolution::SixteenPoints, CompassDirection::N);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_north_north_east() {
+        from_degrees_with_resolution_test(11.25, CompassResolution::SixteenPoints, CompassDirection::NNE);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_north_east() {
+        from_degrees_with_resolution_test(33.75, CompassResolution::SixteenPoints, CompassDirection::NE);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_east_north_east() {
+        from_degrees_with_resolution_test(56.25, CompassResolution::SixteenPoints, CompassDirection::ENE);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_east() {
+        from_degrees_with_resolution_test(78.75, CompassResolution::SixteenPoints, CompassDirection::E);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_east_south_east() {
+        from_degrees_with_resolution_test(101.25, CompassResolution::SixteenPoints, CompassDirection::ESE);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_south_east() {
+        from_degrees_with_resolution_test(123.75, CompassResolution::SixteenPoints, CompassDirection::SE);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_south_south_east() {
+        from_degrees_with_resolution_test(146.25, CompassResolution::SixteenPoints, CompassDirection::SSE);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_south() {
+        from_degrees_with_resolution_test(168.75, CompassResolution::SixteenPoints, CompassDirection::S);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_south_south_west() {
+        from_degrees_with_resolution_test(191.25, CompassResolution::SixteenPoints, CompassDirection::SSW);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_south_west() {
+        from_degrees_with_resolution_test(213.75, CompassResolution::SixteenPoints, CompassDirection::SW);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_west_south_west() {
+        from_degrees_with_resolution_test(236.25, CompassResolution::SixteenPoints, CompassDirection::WSW);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_west() {
+        from_degrees_with_resolution_test(258.75, CompassResolution::SixteenPoints, CompassDirection::W);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_west_north_west() {
+        from_degrees_with_resolution_test(281.25, CompassResolution::SixteenPoints, CompassDirection::WNW);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_north_west() {
+        from_degrees_with_resolution_test(303.75, CompassResolution::SixteenPoints, CompassDirection::NW);
+    }
+
+    #[test]
+    fn from_degrees_sixteen_points_north_north_west() {
+        from_degrees_with_resolution_test(326.25, CompassResolution::SixteenPoints, CompassDirection::NNW);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north() {
+        from_degrees_with_resolution_test(-5.625, CompassResolution::ThirtyTwoPoints, CompassDirection::N);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north_by_east() {
+        from_degrees_with_resolution_test(5.625, CompassResolution::ThirtyTwoPoints, CompassDirection::NbE);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north_north_east() {
+        from_degrees_with_resolution_test(16.875, CompassResolution::ThirtyTwoPoints, CompassDirection::NNE);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north_east_by_north() {
+        from_degrees_with_resolution_test(28.125, CompassResolution::ThirtyTwoPoints, CompassDirection::NEbN);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north_east() {
+        from_degrees_with_resolution_test(39.375, CompassResolution::ThirtyTwoPoints, CompassDirection::NE);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north_east_by_east() {
+        from_degrees_with_resolution_test(50.625, CompassResolution::ThirtyTwoPoints, CompassDirection::NEbE);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_east_north_east() {
+        from_degrees_with_resolution_test(61.875, CompassResolution::ThirtyTwoPoints, CompassDirection::ENE);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_east_by_north() {
+        from_degrees_with_resolution_test(73.125, CompassResolution::ThirtyTwoPoints, CompassDirection::EbN);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_east() {
+        from_degrees_with_resolution_test(84.375, CompassResolution::ThirtyTwoPoints, CompassDirection::E);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_east_by_south() {
+        from_degrees_with_resolution_test(95.625, CompassResolution::ThirtyTwoPoints, CompassDirection::EbS);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_east_south_east() {
+        from_degrees_with_resolution_test(106.875, CompassResolution::ThirtyTwoPoints, CompassDirection::ESE);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south_east_by_east() {
+        from_degrees_with_resolution_test(118.125, CompassResolution::ThirtyTwoPoints, CompassDirection::SEbE);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south_east() {
+        from_degrees_with_resolution_test(129.375, CompassResolution::ThirtyTwoPoints, CompassDirection::SE);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south_east_by_south() {
+        from_degrees_with_resolution_test(140.625, CompassResolution::ThirtyTwoPoints, CompassDirection::SEbS);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south_south_east() {
+        from_degrees_with_resolution_test(151.875, CompassResolution::ThirtyTwoPoints, CompassDirection::SSE);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south_by_east() {
+        from_degrees_with_resolution_test(163.125, CompassResolution::ThirtyTwoPoints, CompassDirection::SbE);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south() {
+        from_degrees_with_resolution_test(174.375, CompassResolution::ThirtyTwoPoints, CompassDirection::S);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south_by_west() {
+        from_degrees_with_resolution_test(185.625, CompassResolution::ThirtyTwoPoints, CompassDirection::SbW);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south_south_west() {
+        from_degrees_with_resolution_test(196.875, CompassResolution::ThirtyTwoPoints, CompassDirection::SSW);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south_west_by_south() {
+        from_degrees_with_resolution_test(208.125, CompassResolution::ThirtyTwoPoints, CompassDirection::SWbS);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south_west() {
+        from_degrees_with_resolution_test(219.375, CompassResolution::ThirtyTwoPoints, CompassDirection::SW);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_south_west_by_west() {
+        from_degrees_with_resolution_test(230.625, CompassResolution::ThirtyTwoPoints, CompassDirection::SWbW);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_west_south_west() {
+        from_degrees_with_resolution_test(241.875, CompassResolution::ThirtyTwoPoints, CompassDirection::WSW);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_west_by_south() {
+        from_degrees_with_resolution_test(253.125, CompassResolution::ThirtyTwoPoints, CompassDirection::WbS);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_west() {
+        from_degrees_with_resolution_test(264.375, CompassResolution::ThirtyTwoPoints, CompassDirection::W);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_west_by_north() {
+        from_degrees_with_resolution_test(275.625, CompassResolution::ThirtyTwoPoints, CompassDirection::WbN);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_west_north_west() {
+        from_degrees_with_resolution_test(286.875, CompassResolution::ThirtyTwoPoints, CompassDirection::WNW);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north_west_by_west() {
+        from_degrees_with_resolution_test(298.125, CompassResolution::ThirtyTwoPoints, CompassDirection::NWbW);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north_west() {
+        from_degrees_with_resolution_test(309.375, CompassResolution::ThirtyTwoPoints, CompassDirection::NW);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north_west_by_north() {
+        from_degrees_with_resolution_test(320.625, CompassResolution::ThirtyTwoPoints, CompassDirection::NWbN);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north_north_west() {
+        from_degrees_with_resolution_test(331.875, CompassResolution::ThirtyTwoPoints, CompassDirection::NNW);
+    }
+
+    #[test]
+    fn from_degrees_thirty_two_points_north_by_west() {
+        from_degrees_with_resolution_test(343.125, CompassResolution::ThirtyTwoPoints, CompassDirection::NbW);
+    }
+
+    #[test]
+    fn central_bearings() {
+        assert_eq!(CompassDirection::N.central_bearing(), 0.);
+        assert_eq!(CompassDirection::NbE.central_bearing(), 11.25);
+        assert_eq!(CompassDirection::NNE.central_bearing(), 22.5);
+        assert_eq!(CompassDirection::SW.central_bearing(), 225.);
+        assert_eq!(CompassDirection::NbW.central_bearing(), 348.75);
+
+        for resolution in [CompassResolution::EightPoints, CompassResolution::SixteenPoints, CompassResolution::ThirtyTwoPoints] {
+            let directions = CompassDirection::ALL.iter().step_by(32 / resolution.points());
+
+            for &direction in directions {
+                let bearing = direction.central_bearing();
+                assert_eq!(CompassDirection::from_degrees_with_resolution(bearing, resolution).unwrap(), direction);
+            }
+        }
+    }
+
+    #[test]
+    fn full_names() {
+        assert_eq!(CompassDirection::NNE.name(Locale::English), "North-northeast");
+        assert_eq!(CompassDirection::NNE.name(Locale::German), "Nordnordost");
+        assert_eq!(CompassDirection::NNE.name(Locale::French), "Nord-nord-est");
+        assert_eq!(CompassDirection::SWbW.name(Locale::English), "Southwest by west");
+        assert_eq!(format!("{:#}", CompassDirection::WNW), "West-northwest");
+        assert_eq!(CompassDirection::WNW.to_string(), "WNW");
+    }
+
+    #[test]
+    fn localised_abbreviations() {
+        assert_eq!(CompassDirection::E.abbreviation(Locale::English), "E");
+        assert_eq!(CompassDirection::E.abbreviation(Locale::German), "O");
+        assert_eq!(CompassDirection::NE.abbreviation(Locale::German), "NO");
+        assert_eq!(CompassDirection::W.abbreviation(Locale::French), "O");
+        assert_eq!(CompassDirection::NW.abbreviation(Locale::French), "NO");
+        assert_eq!(CompassDirection::SW.to_string(), "SW");
+    }
+
     #[test]
     fn from_invalid_degrees() {
         assert!(CompassDirection::from_degrees(f32::NAN).is_err());
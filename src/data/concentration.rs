@@ -0,0 +1,35 @@
+use std::fmt::{self, Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::data::Measurement;
+
+/// Mass concentration of a substance in the air, e.g. a pollutant or water vapour.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Concentration {
+    MicrogramsPerCubicMeter(f32),
+    GramsPerCubicMeter(f32),
+}
+
+impl Display for Concentration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&f32::from(*self), f)?;
+        write!(f, " {}", self.unit())
+    }
+}
+
+impl From<Concentration> for f32 {
+    fn from(value: Concentration) -> Self {
+        match value {
+            Concentration::MicrogramsPerCubicMeter(value) => value,
+            Concentration::GramsPerCubicMeter(value) => value,
+        }
+    }
+}
+
+impl Measurement for Concentration {
+    fn unit(&self) -> &'static str {
+        match self {
+            Concentration::MicrogramsPerCubicMeter(_) => "µg/m³",
+            Concentration::GramsPerCubicMeter(_) => "g/m³",
+        }
+    }
+}
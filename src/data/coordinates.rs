@@ -1,65 +1,609 @@
+use std::borrow::Cow;
 use std::fmt::{self, Display, Formatter};
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+use serde::{Deserialize, Serialize};
+use crate::data::{geohash, maidenhead, plus_code};
 
-pub type Latitude = Coordinate<-9000, 9000, 'S', 'N'>;
-pub type Longitude = Coordinate<-18000, 18000, 'W', 'E'>;
+/// Micro-degrees, about 0.1 m at the equator.
+pub const DECIMALS: u32 = 6;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct Coordinate<const MIN: i32, const MAX: i32, const NEG_PREF: char, const POS_PREF: char> {
-    value_times_100: i32,
+pub type Latitude = Coordinate<-90, 90, DECIMALS, 'S', 'N'>;
+pub type Longitude = Coordinate<-180, 180, DECIMALS, 'W', 'E'>;
+
+/// Angle within [MIN, MAX] degrees, stored as an integer multiple of 10^-DECIMALS degrees so that equality is exact.
+/// Values in between are rounded half to even.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Coordinate<const MIN: i32, const MAX: i32, const DECIMALS: u32, const NEG_PREF: char, const POS_PREF: char> {
+    units: i64,
+}
+
+impl<const MIN: i32, const MAX: i32, const DECIMALS: u32, const NEG_PREF: char, const POS_PREF: char>
+Coordinate<MIN, MAX, DECIMALS, NEG_PREF, POS_PREF> {
+    const UNITS_PER_DEGREE: i64 = 10_i64.pow(DECIMALS);
+
+    /// The exact value as a multiple of 10^-DECIMALS degrees.
+    pub fn units(&self) -> i64 {
+        self.units
+    }
 }
 
-impl<const MIN: i32, const MAX: i32, const NEG_PREF: char, const POS_PREF: char>
-TryFrom<f32> for Coordinate<MIN, MAX, NEG_PREF, POS_PREF> {
+impl<const MIN: i32, const MAX: i32, const DECIMALS: u32, const NEG_PREF: char, const POS_PREF: char>
+TryFrom<f64> for Coordinate<MIN, MAX, DECIMALS, NEG_PREF, POS_PREF> {
     type Error = Error;
 
-    fn try_from(value: f32) -> Result<Self, Self::Error> {
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
         if !value.is_finite() {
             return Err(Error::InvalidFloat);
         }
 
-        let min = (MIN as f32) / 100.;
-        let max = (MAX as f32) / 100.;
+        let (min, max) = (MIN as f64, MAX as f64);
 
         if (min..=max).contains(&value) {
-            Ok(Coordinate { value_times_100: (100. * value) as i32 })
+            Ok(Coordinate { units: (value * Self::UNITS_PER_DEGREE as f64).round_ties_even() as i64 })
         } else {
             Err(Error::OutOfRange { min, max, actual: value })
         }
     }
 }
 
-impl<const MIN: i32, const MAX: i32, const NEG_PREF: char, const POS_PREF: char>
-From<Coordinate<MIN, MAX, NEG_PREF, POS_PREF>> for f32 {
-    fn from(coordinate: Coordinate<MIN, MAX, NEG_PREF, POS_PREF>) -> Self {
-        (coordinate.value_times_100 as f32) / 100.
+impl<const MIN: i32, const MAX: i32, const DECIMALS: u32, const NEG_PREF: char, const POS_PREF: char>
+TryFrom<f32> for Coordinate<MIN, MAX, DECIMALS, NEG_PREF, POS_PREF> {
+    type Error = Error;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Coordinate::try_from(value as f64)
     }
 }
 
-impl<const MIN: i32, const MAX: i32, const NEG_PREF: char, const POS_PREF: char>
-Display for Coordinate<MIN, MAX, NEG_PREF, POS_PREF> {
+impl<const MIN: i32, const MAX: i32, const DECIMALS: u32, const NEG_PREF: char, const POS_PREF: char>
+From<Coordinate<MIN, MAX, DECIMALS, NEG_PREF, POS_PREF>> for f64 {
+    fn from(coordinate: Coordinate<MIN, MAX, DECIMALS, NEG_PREF, POS_PREF>) -> Self {
+        coordinate.units as f64 / Coordinate::<MIN, MAX, DECIMALS, NEG_PREF, POS_PREF>::UNITS_PER_DEGREE as f64
+    }
+}
+
+impl<const MIN: i32, const MAX: i32, const DECIMALS: u32, const NEG_PREF: char, const POS_PREF: char>
+From<Coordinate<MIN, MAX, DECIMALS, NEG_PREF, POS_PREF>> for f32 {
+    fn from(coordinate: Coordinate<MIN, MAX, DECIMALS, NEG_PREF, POS_PREF>) -> Self {
+        f64::from(coordinate) as f32
+    }
+}
+
+/// Written with integer arithmetic and without trailing zeros, so that all digits stored are shown exactly.
+impl<const MIN: i32, const MAX: i32, const DECIMALS: u32, const NEG_PREF: char, const POS_PREF: char>
+Display for Coordinate<MIN, MAX, DECIMALS, NEG_PREF, POS_PREF> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let value = f32::from(*self);
+        let units = self.units.unsigned_abs();
+        let units_per_degree = Self::UNITS_PER_DEGREE as u64;
+        let hemisphere = if self.units < 0 { NEG_PREF } else { POS_PREF };
 
-        write!(
-            f,
-            "{}°{}",
-            value.abs(),
-            if value < 0. { NEG_PREF } else { POS_PREF },
-        )
+        write!(f, "{}", units / units_per_degree)?;
+
+        let fraction = format!("{:0width$}", units % units_per_degree, width = DECIMALS as usize);
+        let fraction = fraction.trim_end_matches('0');
+
+        if !fraction.is_empty() {
+            write!(f, ".{}", fraction)?;
+        }
+
+        write!(f, "°{}", hemisphere)
     }
 }
 
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum Error {
     #[error("The value must lie in [{min}, {max}], but it is {actual}")]
-    OutOfRange { min: f32, max: f32, actual: f32 },
+    OutOfRange { min: f64, max: f64, actual: f64 },
 
     #[error("Infinity and NaN can not be interpreted as a coordinate")]
     InvalidFloat,
+
+    #[error("No coordinates given")]
+    Empty,
+
+    #[error("Unexpected character '{0}'")]
+    UnexpectedCharacter(char),
+
+    #[error("\"{0}\" is not a number")]
+    InvalidNumber(String),
+
+    #[error("A coordinate is missing its degrees")]
+    MissingDegrees,
+
+    #[error("The {0} must follow the {1}")]
+    MisplacedComponent(&'static str, &'static str),
+
+    #[error("The value {0} must be whole, as it is followed by a smaller unit")]
+    FractionalComponent(f64),
+
+    #[error("Minutes and seconds must lie in [0, 60), but one is {0}")]
+    InvalidMinutesOrSeconds(f64),
+
+    #[error("A negative value cannot be combined with the hemisphere {0}")]
+    SignWithHemisphere(char),
+
+    #[error("The hemispheres {0} and {1} do not describe a latitude and a longitude")]
+    InvalidHemispheres(char, char),
+
+    #[error("Expected a latitude and a longitude, but found {0} values")]
+    WrongNumberOfValues(usize),
+
+    #[error("\"{0}\" is not a valid geo URI")]
+    InvalidGeoUri(String),
+
+    #[error("No coordinates found in the link \"{0}\"")]
+    NoCoordinatesInLink(String),
+
+    #[error(transparent)]
+    Geohash(#[from] geohash::Error),
+
+    #[error(transparent)]
+    Maidenhead(#[from] maidenhead::Error),
+
+    #[error(transparent)]
+    PlusCode(#[from] plus_code::Error),
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Coordinates {
     pub latitude: Latitude,
     pub longitude: Longitude,
 }
+
+impl Display for Coordinates {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.latitude, self.longitude)
+    }
+}
+
+impl Coordinates {
+    /// Centre of the cell between the given bounds in degrees, as described by a geohash, Maidenhead locator or Plus Code.
+    pub(crate) fn cell_centre(south: f64, west: f64, north: f64, east: f64) -> Coordinates {
+        Coordinates {
+            latitude: Latitude::try_from(((south + north) / 2.).clamp(-90., 90.)).expect("The latitude is clamped into its range"),
+            longitude: Longitude::try_from(((west + east) / 2.).clamp(-180., 180.)).expect("The longitude is clamped into its range"),
+        }
+    }
+}
+
+/// Accepts decimal degrees (`52.52, 13.40`), hemispheres as prefix or suffix (`52.52N 13.40E`),
+/// degrees, minutes and seconds (`52°31'12"N 13°24'E`), `geo:` URIs, links to Google Maps or OpenStreetMap,
+/// full Plus Codes, Maidenhead locators and geohashes. Without hemispheres, the latitude comes first.
+/// Text that is both a valid Maidenhead locator and geohash, like `JO62`, is read as a locator.
+impl FromStr for Coordinates {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            Err(Error::Empty)
+        } else if s.get(..4).is_some_and(|scheme| scheme.eq_ignore_ascii_case("geo:")) {
+            parse_geo_uri(s)
+        } else if s.contains('/') {
+            parse_link(s)
+        } else if Coordinates::looks_like_plus_code(s) {
+            Ok(Coordinates::from_plus_code(s)?)
+        } else if let Ok(coordinates) = Coordinates::from_maidenhead(s) {
+            Ok(coordinates)
+        } else {
+            parse_pair(s).or_else(|error| {
+                if Coordinates::looks_like_geohash(s) { Ok(Coordinates::from_geohash(s)?) } else { Err(error) }
+            })
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Unit {
+    Degrees,
+    Minutes,
+    Seconds,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token {
+    Number(f64, Unit),
+    Hemisphere(char),
+    Separator,
+}
+
+/// Numbers without a unit symbol are taken as degrees.
+fn tokenize(text: &str) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            _ if c.is_whitespace() => {},
+            ',' | ';' => tokens.push(Token::Separator),
+            '+' | '-' | '\u{2212}' | '.' | '0'..='9' => {
+                let mut number = String::from(if c == '\u{2212}' { '-' } else { c });
+
+                while let Some(digit) = chars.next_if(|c| c.is_ascii_digit() || *c == '.') {
+                    number.push(digit);
+                }
+
+                let value = number.parse().map_err(|_| Error::InvalidNumber(number))?;
+                tokens.push(Token::Number(value, parse_unit(&mut chars)));
+            },
+            'N' | 'S' | 'E' | 'W' | 'n' | 's' | 'e' | 'w' => tokens.push(Token::Hemisphere(c.to_ascii_uppercase())),
+            _ => return Err(Error::UnexpectedCharacter(c)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn parse_unit(chars: &mut Peekable<Chars<'_>>) -> Unit {
+    if chars.next_if(|c| matches!(c, '°' | 'º')).is_some() {
+        Unit::Degrees
+    } else if chars.next_if(|c| matches!(c, '"' | '″' | '”')).is_some() {
+        Unit::Seconds
+    } else if chars.next_if(|c| matches!(c, '\'' | '′' | '’')).is_some() {
+        // Two primes are a common replacement for the double prime
+        match chars.next_if_eq(&'\'') {
+            Some(_) => Unit::Seconds,
+            None => Unit::Minutes,
+        }
+    } else {
+        Unit::Degrees
+    }
+}
+
+/// A single latitude or longitude as written, before it is known which of both it is.
+#[derive(Clone, Copy, Debug, Default)]
+struct RawCoordinate {
+    hemisphere: Option<char>,
+    degrees: Option<f64>,
+    minutes: Option<f64>,
+    seconds: Option<f64>,
+}
+
+impl RawCoordinate {
+    fn is_empty(&self) -> bool {
+        self.hemisphere.is_none() && self.degrees.is_none()
+    }
+
+    fn is_latitude(&self) -> Option<bool> {
+        self.hemisphere.map(|hemisphere| matches!(hemisphere, 'N' | 'S'))
+    }
+
+    fn push(&mut self, value: f64, unit: Unit) -> Result<(), Error> {
+        match unit {
+            Unit::Degrees => self.degrees = Some(value),
+            Unit::Minutes if self.degrees.is_some() && self.minutes.is_none() => self.minutes = Some(value),
+            Unit::Minutes => return Err(Error::MisplacedComponent("minutes", "degrees")),
+            Unit::Seconds if self.minutes.is_some() && self.seconds.is_none() => self.seconds = Some(value),
+            Unit::Seconds => return Err(Error::MisplacedComponent("seconds", "minutes")),
+        }
+
+        Ok(())
+    }
+
+    fn value(&self) -> Result<f64, Error> {
+        let degrees = self.degrees.ok_or(Error::MissingDegrees)?;
+
+        for (value, smaller_unit) in [(Some(degrees), self.minutes), (self.minutes, self.seconds)] {
+            if let (Some(value), Some(_)) = (value, smaller_unit) {
+                if value.fract() != 0. {
+                    return Err(Error::FractionalComponent(value));
+                }
+            }
+        }
+
+        for value in [self.minutes, self.seconds].into_iter().flatten() {
+            if !(0. ..60.).contains(&value) {
+                return Err(Error::InvalidMinutesOrSeconds(value));
+            }
+        }
+
+        let magnitude = degrees.abs() + self.minutes.unwrap_or(0.) / 60. + self.seconds.unwrap_or(0.) / 3600.;
+
+        let value = match self.hemisphere {
+            Some(hemisphere) if degrees.is_sign_negative() => return Err(Error::SignWithHemisphere(hemisphere)),
+            Some('S' | 'W') => -magnitude,
+            _ if degrees.is_sign_negative() => -magnitude,
+            _ => magnitude,
+        };
+
+        Ok(value)
+    }
+}
+
+/// Splits the tokens into coordinates at separators, hemispheres and degrees following a complete coordinate.
+fn group(tokens: Vec<Token>) -> Result<Vec<RawCoordinate>, Error> {
+    let mut raw_coordinates = Vec::new();
+    let mut current = RawCoordinate::default();
+
+    for token in tokens {
+        match token {
+            Token::Separator => {
+                if !current.is_empty() {
+                    raw_coordinates.push(current);
+                    current = RawCoordinate::default();
+                }
+            },
+            Token::Hemisphere(hemisphere) => {
+                if current.degrees.is_some() {
+                    // A suffix, or the prefix of the next coordinate if this one already has a prefix
+                    if current.hemisphere.is_none() {
+                        current.hemisphere = Some(hemisphere);
+                        raw_coordinates.push(current);
+                        current = RawCoordinate::default();
+                    } else {
+                        raw_coordinates.push(current);
+                        current = RawCoordinate { hemisphere: Some(hemisphere), ..RawCoordinate::default() };
+                    }
+                } else if current.hemisphere.is_none() {
+                    current.hemisphere = Some(hemisphere);
+                } else {
+                    return Err(Error::MissingDegrees);
+                }
+            },
+            Token::Number(value, Unit::Degrees) if current.degrees.is_some() => {
+                raw_coordinates.push(current);
+                current = RawCoordinate::default();
+                current.push(value, Unit::Degrees)?;
+            },
+            Token::Number(value, unit) => current.push(value, unit)?,
+        }
+    }
+
+    if !current.is_empty() {
+        raw_coordinates.push(current);
+    }
+
+    Ok(raw_coordinates)
+}
+
+fn parse_pair(text: &str) -> Result<Coordinates, Error> {
+    let raw_coordinates = group(tokenize(text)?)?;
+
+    let [first, second] = raw_coordinates[..] else {
+        return Err(Error::WrongNumberOfValues(raw_coordinates.len()));
+    };
+
+    let (latitude, longitude) = match (first.is_latitude(), second.is_latitude()) {
+        (Some(first_is_latitude), Some(second_is_latitude)) if first_is_latitude == second_is_latitude => {
+            return Err(Error::InvalidHemispheres(first.hemisphere.unwrap_or_default(), second.hemisphere.unwrap_or_default()));
+        },
+        (Some(false), _) | (_, Some(true)) => (second, first),
+        _ => (first, second),
+    };
+
+    Ok(Coordinates {
+        latitude: Latitude::try_from(latitude.value()?)?,
+        longitude: Longitude::try_from(longitude.value()?)?,
+    })
+}
+
+fn parse_decimals(latitude: &str, longitude: &str) -> Result<Coordinates, Error> {
+    let parse = |value: &str| value.trim().parse::<f64>().map_err(|_| Error::InvalidNumber(value.to_owned()));
+
+    Ok(Coordinates {
+        latitude: Latitude::try_from(parse(latitude)?)?,
+        longitude: Longitude::try_from(parse(longitude)?)?,
+    })
+}
+
+fn decode(value: &str) -> Cow<'_, str> {
+    urlencoding::decode(value).unwrap_or(Cow::Borrowed(value))
+}
+
+/// RFC 5870, e.g. `geo:52.52,13.40;u=10`, including the `geo:0,0?q=52.52,13.40(Label)` variant of Android.
+fn parse_geo_uri(uri: &str) -> Result<Coordinates, Error> {
+    let (path, query) = uri[4..].split_once('?').unwrap_or((&uri[4..], ""));
+
+    let position = query.split('&')
+        .find_map(|parameter| parameter.strip_prefix("q="))
+        .and_then(|position| parse_pair(decode(position).split('(').next().unwrap_or_default()).ok());
+
+    if let Some(position) = position {
+        return Ok(position);
+    }
+
+    let path = path.split(';').next().unwrap_or_default();
+
+    // The optional third value is the altitude
+    match path.split(',').collect::<Vec<_>>()[..] {
+        [latitude, longitude] | [latitude, longitude, _] => parse_decimals(latitude, longitude),
+        _ => Err(Error::InvalidGeoUri(uri.to_owned())),
+    }
+}
+
+/// Looks for the markers and map centres used in the links of Google Maps and OpenStreetMap, in that order.
+fn parse_link(link: &str) -> Result<Coordinates, Error> {
+    let (rest, fragment) = link.split_once('#').unwrap_or((link, ""));
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+    let parameters = query.split('&').chain(fragment.split('&'))
+        .filter_map(|parameter| parameter.split_once('='))
+        .map(|(key, value)| (key, decode(value)))
+        .collect::<Vec<_>>();
+
+    let parameter = |key: &str| parameters.iter().find(|(k, _)| *k == key).map(|(_, value)| value.as_ref());
+
+    let decimal_parameters = |latitude_key, longitude_key| {
+        parse_decimals(parameter(latitude_key)?, parameter(longitude_key)?).ok()
+    };
+
+    let pair_parameter = |key| parse_pair(parameter(key)?).ok();
+
+    // Places in Google Maps, e.g. `/data=!3m1!4b1!4m6!3m5!1s0x0:0x0!8m2!3d52.52!4d13.40`
+    let google_place = || {
+        let (_, rest) = path.rsplit_once("!3d")?;
+        let (latitude, longitude) = rest.split_once("!4d")?;
+        parse_decimals(latitude, longitude.split('!').next()?).ok()
+    };
+
+    // Map centre in Google Maps, e.g. `/maps/@52.52,13.40,15z`
+    let google_centre = || {
+        let (_, rest) = path.split_once('@')?;
+        let mut values = rest.split([',', '/']);
+        parse_decimals(values.next()?, values.next()?).ok()
+    };
+
+    // Map centre in OpenStreetMap, e.g. `#map=15/52.52/13.40`
+    let osm_centre = || {
+        let mut values = parameter("map")?.split('/').skip(1);
+        parse_decimals(values.next()?, values.next()?).ok()
+    };
+
+    decimal_parameters("mlat", "mlon")
+        .or_else(google_place)
+        .or_else(|| ["q", "query", "ll", "destination"].into_iter().find_map(pair_parameter))
+        .or_else(google_centre)
+        .or_else(osm_centre)
+        .or_else(|| decimal_parameters("lat", "lon"))
+        .ok_or(Error::NoCoordinatesInLink(link.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::coordinates::{Coordinate, Error};
+    use crate::data::{Coordinates, Latitude, Longitude};
+
+    type Centidegrees = Coordinate<-90, 90, 2, 'S', 'N'>;
+
+    fn assert_parses(text: &str, latitude: f32, longitude: f32) {
+        let coordinates = text.parse::<Coordinates>().unwrap_or_else(|error| panic!("{text}: {error}"));
+        assert!((f32::from(coordinates.latitude) - latitude).abs() <= 0.01, "{text}: {coordinates}");
+        assert!((f32::from(coordinates.longitude) - longitude).abs() <= 0.01, "{text}: {coordinates}");
+    }
+
+    #[test]
+    fn rounding_half_to_even() {
+        let centidegrees = |value: f64| Centidegrees::try_from(value).unwrap().to_string();
+
+        assert_eq!(centidegrees(0.29), "0.29°N");
+        assert_eq!(centidegrees(0.125), "0.12°N");
+        assert_eq!(centidegrees(0.375), "0.38°N");
+        assert_eq!(centidegrees(-0.125), "0.12°S");
+        assert_eq!(centidegrees(-0.004), "0°N");
+        assert_eq!(centidegrees(90.), "90°N");
+    }
+
+    #[test]
+    fn range_limits() {
+        assert!(Latitude::try_from(-90.).is_ok());
+        assert!(Latitude::try_from(90.000001).is_err());
+        assert!(Longitude::try_from(-180.).is_ok());
+        assert!(Longitude::try_from(180.000001).is_err());
+        assert!(Longitude::try_from(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn f32_round_trips() {
+        let values: [f32; 10] = [0., 0.000001, -0.000001, 1.234567, -33.86882, 52.520008, 89.999999, -90., 179.999999, -180.];
+
+        for value in values {
+            assert_eq!(f32::from(Longitude::try_from(value).unwrap()), value);
+        }
+    }
+
+    #[test]
+    fn f64_and_display_round_trips() {
+        let micro_degrees = |units: i64| units as f64 / 1_000_000.;
+        let latitudes = (-90_000_000..=90_000_000).step_by(1_234_567).chain([-90_000_000, -1, 0, 1, 90_000_000]);
+
+        for (index, latitude_units) in latitudes.enumerate() {
+            let longitude_units = 180_000_000 - 2_345_678 * index as i64;
+
+            let coordinates = Coordinates {
+                latitude: Latitude::try_from(micro_degrees(latitude_units)).unwrap(),
+                longitude: Longitude::try_from(micro_degrees(longitude_units)).unwrap(),
+            };
+
+            assert_eq!(f64::from(coordinates.latitude), micro_degrees(latitude_units));
+            assert_eq!(Longitude::try_from(f64::from(coordinates.longitude)).unwrap(), coordinates.longitude);
+            assert_eq!(coordinates.to_string().parse::<Coordinates>().unwrap(), coordinates);
+        }
+
+        assert_eq!(Latitude::try_from(-33.868820).unwrap().to_string(), "33.86882°S");
+    }
+
+    #[test]
+    fn parse_decimal_pairs() {
+        assert_parses("52.52, 13.40", 52.52, 13.40);
+        assert_parses("52.52,13.40", 52.52, 13.40);
+        assert_parses("  52.52 13.40 ", 52.52, 13.40);
+        assert_parses("-33.87; 151.21", -33.87, 151.21);
+        assert_parses("+40.71 -74.01", 40.71, -74.01);
+    }
+
+    #[test]
+    fn parse_hemispheres() {
+        assert_parses("52.52N 13.40E", 52.52, 13.40);
+        assert_parses("33.87 S, 151.21 E", -33.87, 151.21);
+        assert_parses("N40.71 W74.01", 40.71, -74.01);
+        assert_parses("74.01w 40.71n", 40.71, -74.01);
+        assert_parses("13.40E, 52.52", 52.52, 13.40);
+    }
+
+    #[test]
+    fn parse_degrees_minutes_seconds() {
+        assert_parses("52°31'12\"N 13°24'E", 52.52, 13.40);
+        assert_parses("52° 31.2' N, 13° 24' E", 52.52, 13.40);
+        assert_parses("33°52′10″S 151°12′30″E", -33.8694, 151.2083);
+        assert_parses("40°42'46''N 74°0'22''W", 40.7128, -74.0061);
+        assert_parses("-33°52'10\", 151°12'30\"", -33.8694, 151.2083);
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let coordinates = "-33.87, -151.21".parse::<Coordinates>().unwrap();
+        assert_eq!(coordinates.to_string().parse::<Coordinates>().unwrap(), coordinates);
+    }
+
+    #[test]
+    fn parse_geo_uris() {
+        assert_parses("geo:52.52,13.40", 52.52, 13.40);
+        assert_parses("GEO:52.52,13.40,34;u=10", 52.52, 13.40);
+        assert_parses("geo:0,0?q=52.52,13.40(Berlin)", 52.52, 13.40);
+    }
+
+    #[test]
+    fn parse_links() {
+        assert_parses("https://www.google.com/maps/@52.52,13.40,15z", 52.52, 13.40);
+        assert_parses("https://www.google.com/maps/place/Berlin/@52.50,13.20,11z/data=!3m1!4b1!4m6!3m5!8m2!3d52.52!4d13.40!16s", 52.52, 13.40);
+        assert_parses("https://maps.google.com/?q=52.52%2C13.40", 52.52, 13.40);
+        assert_parses("https://www.openstreetmap.org/#map=15/52.52/13.40", 52.52, 13.40);
+        assert_parses("https://www.openstreetmap.org/?mlat=52.52&mlon=13.40#map=12/52.50/13.20", 52.52, 13.40);
+        assert_parses("https://example.com/stormy_skies/?lat=52.52&lon=13.4", 52.52, 13.40);
+    }
+
+    #[test]
+    fn parse_location_codes() {
+        assert_parses("9F4MGCC4+22", 52.52, 13.405);
+        assert_parses(" 9f4mgcc4+22 ", 52.52, 13.405);
+        assert_parses("u33dc0c", 52.52, 13.405);
+        assert_parses("JO62qm", 52.52, 13.375);
+        assert_parses("+52.52, +13.40", 52.52, 13.40);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |text: &str| text.parse::<Coordinates>().unwrap_err();
+
+        assert!(matches!(error("  "), Error::Empty));
+        assert!(matches!(error("52.52, 13.40x"), Error::UnexpectedCharacter('x')));
+        assert!(matches!(error("52..5, 13"), Error::InvalidNumber(number) if number == "52..5"));
+        assert!(matches!(error("52.52"), Error::WrongNumberOfValues(1)));
+        assert!(matches!(error("1, 2, 3"), Error::WrongNumberOfValues(3)));
+        assert!(matches!(error("N, 13.40"), Error::MissingDegrees));
+        assert!(matches!(error("31' 13.40"), Error::MisplacedComponent("minutes", "degrees")));
+        assert!(matches!(error("52.5°30'N 13E"), Error::FractionalComponent(_)));
+        assert!(matches!(error("52°61'N 13E"), Error::InvalidMinutesOrSeconds(_)));
+        assert!(matches!(error("-52.52S 13.40E"), Error::SignWithHemisphere('S')));
+        assert!(matches!(error("52.52N 13.40S"), Error::InvalidHemispheres('N', 'S')));
+        assert!(matches!(error("91, 13.40"), Error::OutOfRange { .. }));
+        assert!(matches!(error("geo:52.52"), Error::InvalidGeoUri(_)));
+        assert!(matches!(error("https://www.google.com/maps/search/Berlin"), Error::NoCoordinatesInLink(_)));
+        assert!(matches!(error("GCC4+22"), Error::PlusCode(_)));
+        assert!(matches!(error("u33dc0cu33dc0"), Error::Geohash(_)));
+        assert!(matches!(error("52"), Error::WrongNumberOfValues(1)));
+    }
+}
@@ -0,0 +1,352 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Self, Error> {
+        if !(1..=12).contains(&month) {
+            return Err(Error::InvalidMonth(month));
+        }
+
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(Error::InvalidDay { year, month, day });
+        }
+
+        Ok(Date { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Number of days from this date to the other one, negative if the other one lies before.
+    pub fn days_until(&self, other: Date) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+
+    /// Days since 1970-01-01 in the proleptic Gregorian calendar, see http://howardhinnant.github.io/date_algorithms.html.
+    pub fn days_since_epoch(&self) -> i64 {
+        let month = self.month as i64;
+        let day = self.day as i64;
+        let year = self.year as i64 - (month <= 2) as i64;
+
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Inverse of [`Date::days_since_epoch`].
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+        let year = era * 400 + year_of_era + (month <= 2) as i64;
+
+        Date { year: year as i32, month: month as u8, day: day as u8 }
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl FromStr for Date {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, '-');
+
+        let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(Error::InvalidFormat(s.to_owned()));
+        };
+
+        let parse_error = |_| Error::InvalidFormat(s.to_owned());
+
+        Date::new(
+            year.parse().map_err(parse_error)?,
+            month.parse().map_err(parse_error)?,
+            day.parse().map_err(parse_error)?,
+        )
+    }
+}
+
+impl TryFrom<String> for Date {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Date> for String {
+    fn from(value: Date) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct DateTime {
+    date: Date,
+    hour: u8,
+    minute: u8,
+}
+
+impl DateTime {
+    pub fn new(date: Date, hour: u8, minute: u8) -> Result<Self, Error> {
+        if hour > 23 || minute > 59 {
+            return Err(Error::InvalidTime { hour, minute });
+        }
+
+        Ok(DateTime { date, hour, minute })
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn time_of_day(&self) -> String {
+        format!("{:02}:{:02}", self.hour, self.minute)
+    }
+
+    /// Minutes since 1970-01-01T00:00 in the same time zone.
+    pub fn minutes_since_epoch(&self) -> i64 {
+        self.date.days_since_epoch() * 1440 + self.hour as i64 * 60 + self.minute as i64
+    }
+
+    /// Inverse of [`DateTime::minutes_since_epoch`].
+    pub fn from_minutes_since_epoch(minutes: i64) -> Self {
+        let minute_of_day = minutes.rem_euclid(1440);
+
+        DateTime {
+            date: Date::from_days_since_epoch(minutes.div_euclid(1440)),
+            hour: (minute_of_day / 60) as u8,
+            minute: (minute_of_day % 60) as u8,
+        }
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((date, time)) = s.split_once('T') else {
+            return Err(Error::InvalidFormat(s.to_owned()));
+        };
+
+        let Some((hour, minute)) = time.split_once(':') else {
+            return Err(Error::InvalidFormat(s.to_owned()));
+        };
+
+        let parse_error = |_| Error::InvalidFormat(s.to_owned());
+
+        DateTime::new(
+            date.parse()?,
+            hour.parse().map_err(parse_error)?,
+            minute.parse().map_err(parse_error)?,
+        )
+    }
+}
+
+impl TryFrom<String> for DateTime {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<DateTime> for String {
+    fn from(value: DateTime) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time_of_day())
+    }
+}
+
+/// Range of dates including both the start and the end.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+}
+
+impl DateRange {
+    pub fn new(start: Date, end: Date) -> Result<Self, Error> {
+        if end < start {
+            return Err(Error::InvalidRange { start, end });
+        }
+
+        Ok(DateRange { start, end })
+    }
+
+    pub fn start(&self) -> Date {
+        self.start
+    }
+
+    pub fn end(&self) -> Date {
+        self.end
+    }
+
+    pub fn day_count(&self) -> u32 {
+        self.start.days_until(self.end) as u32 + 1
+    }
+}
+
+impl Display for DateRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} – {}", self.start, self.end)
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("\"{0}\" is not a valid ISO 8601 date or date-time")]
+    InvalidFormat(String),
+
+    #[error("The month must lie in [1, 12], but it is {0}")]
+    InvalidMonth(u8),
+
+    #[error("{year:04}-{month:02} does not have a day {day}")]
+    InvalidDay { year: i32, month: u8, day: u8 },
+
+    #[error("{hour:02}:{minute:02} is not a valid time of day")]
+    InvalidTime { hour: u8, minute: u8 },
+
+    #[error("The range ends on {end}, before it starts on {start}")]
+    InvalidRange { start: Date, end: Date },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{Date, DateRange, DateTime};
+
+    #[test]
+    fn parse_date() {
+        let date = "2024-02-29".parse::<Date>().unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+        assert_eq!(date.to_string(), "2024-02-29");
+    }
+
+    #[test]
+    fn parse_invalid_dates() {
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("1900-02-29".parse::<Date>().is_err());
+        assert!("2024-13-01".parse::<Date>().is_err());
+        assert!("2024-04-31".parse::<Date>().is_err());
+        assert!("2024-04".parse::<Date>().is_err());
+        assert!("yesterday".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn days_between_dates() {
+        let date = |s: &str| s.parse::<Date>().unwrap();
+
+        assert_eq!(date("1970-01-01").days_since_epoch(), 0);
+        assert_eq!(date("2000-03-01").days_since_epoch(), 11_017);
+        assert_eq!(date("1969-12-31").days_since_epoch(), -1);
+        assert_eq!(date("2024-02-28").days_until(date("2024-03-01")), 2);
+        assert_eq!(date("2023-02-28").days_until(date("2023-03-01")), 1);
+        assert_eq!(date("2024-01-01").days_until(date("2023-01-01")), -365);
+    }
+
+    #[test]
+    fn dates_from_days_since_epoch() {
+        for s in ["1970-01-01", "1969-12-31", "2000-02-29", "2000-03-01", "2024-12-31", "1600-01-01", "2400-02-29"] {
+            let date = s.parse::<Date>().unwrap();
+            assert_eq!(Date::from_days_since_epoch(date.days_since_epoch()), date);
+        }
+    }
+
+    #[test]
+    fn date_ranges() {
+        let date = |s: &str| s.parse::<Date>().unwrap();
+
+        let range = DateRange::new(date("2023-12-30"), date("2024-01-02")).unwrap();
+        assert_eq!(range.day_count(), 4);
+        assert_eq!(range.to_string(), "2023-12-30 – 2024-01-02");
+
+        assert_eq!(DateRange::new(date("2024-01-02"), date("2024-01-02")).unwrap().day_count(), 1);
+        assert!(DateRange::new(date("2024-01-02"), date("2024-01-01")).is_err());
+    }
+
+    #[test]
+    fn parse_date_time() {
+        let date_time = "2024-02-10T14:00".parse::<DateTime>().unwrap();
+        assert_eq!(date_time.date(), Date::new(2024, 2, 10).unwrap());
+        assert_eq!((date_time.hour(), date_time.minute()), (14, 0));
+        assert_eq!(date_time.time_of_day(), "14:00");
+        assert_eq!(date_time.to_string(), "2024-02-10T14:00");
+    }
+
+    #[test]
+    fn parse_invalid_date_times() {
+        assert!("2024-02-10T24:00".parse::<DateTime>().is_err());
+        assert!("2024-02-10T12:60".parse::<DateTime>().is_err());
+        assert!("2024-02-10 12:00".parse::<DateTime>().is_err());
+        assert!("2024-02-10T12".parse::<DateTime>().is_err());
+    }
+
+    #[test]
+    fn minutes_since_epoch() {
+        let date_time = |s: &str| s.parse::<DateTime>().unwrap();
+
+        assert_eq!(date_time("1970-01-01T01:05").minutes_since_epoch(), 65);
+        assert_eq!(DateTime::from_minutes_since_epoch(-1), date_time("1969-12-31T23:59"));
+
+        let date_time = date_time("2024-02-29T23:30");
+        assert_eq!(DateTime::from_minutes_since_epoch(date_time.minutes_since_epoch() + 45), "2024-03-01T00:15".parse().unwrap());
+    }
+}
@@ -0,0 +1,199 @@
+//! Quantities derived from the basic measurements, using the formulas published by the respective weather services.
+//! All derived temperatures are given in degrees Celsius.
+
+use crate::data::{Concentration, Percentage, Speed, Temperature};
+
+/// Magnus constants after Alduchov and Eskridge (1996), accurate to 0.1 °C within [-40, 50] °C.
+const MAGNUS_A: f64 = 17.625;
+const MAGNUS_B: f64 = 243.04;
+
+fn celsius(temperature: Temperature) -> f64 {
+    f32::from(temperature.to_celsius()) as f64
+}
+
+fn fahrenheit(temperature: Temperature) -> f64 {
+    f32::from(temperature.to_fahrenheit()) as f64
+}
+
+fn relative_humidity(humidity: Percentage) -> f64 {
+    f32::from(humidity) as f64
+}
+
+/// Saturation vapour pressure over water in hPa, after Bolton (1980).
+fn saturation_vapour_pressure(celsius: f64) -> f64 {
+    6.112 * (17.67 * celsius / (celsius + 243.5)).exp()
+}
+
+/// Magnus formula. Undefined for completely dry air.
+pub fn dew_point(temperature: Temperature, humidity: Percentage) -> Option<Temperature> {
+    if u8::from(humidity) == 0 {
+        return None;
+    }
+
+    let celsius = celsius(temperature);
+    let gamma = relative_humidity(humidity).ln() + MAGNUS_A * celsius / (MAGNUS_B + celsius);
+
+    Some(Temperature::Celsius((MAGNUS_B * gamma / (MAGNUS_A - gamma)) as f32))
+}
+
+/// Apparent temperature in the shade after Steadman (1994), as used by the Australian Bureau of Meteorology.
+pub fn apparent_temperature(temperature: Temperature, humidity: Percentage, wind_speed: Speed) -> Temperature {
+    let celsius = celsius(temperature);
+    let meters_per_second = f32::from(wind_speed.to_meters_per_second()) as f64;
+    let vapour_pressure = relative_humidity(humidity) * 6.105 * (17.27 * celsius / (237.7 + celsius)).exp();
+
+    Temperature::Celsius((celsius + 0.33 * vapour_pressure - 0.70 * meters_per_second - 4.00) as f32)
+}
+
+/// Heat index after the algorithm of the US National Weather Service, see https://www.wpc.ncep.noaa.gov/html/heatindex_equation.shtml.
+/// It is only meaningful from about 27 °C (80 °F) on, below that it stays close to the air temperature.
+pub fn heat_index(temperature: Temperature, humidity: Percentage) -> Temperature {
+    let t = fahrenheit(temperature);
+    let rh = relative_humidity(humidity) * 100.;
+
+    let simple = 0.5 * (t + 61. + (t - 68.) * 1.2 + rh * 0.094);
+
+    if (simple + t) / 2. < 80. {
+        return Temperature::Fahrenheit(simple as f32).to_celsius();
+    }
+
+    let mut heat_index = -42.379
+        + 2.049_015_23 * t
+        + 10.143_331_27 * rh
+        - 0.224_755_41 * t * rh
+        - 0.006_837_83 * t * t
+        - 0.054_817_17 * rh * rh
+        + 0.001_228_74 * t * t * rh
+        + 0.000_852_82 * t * rh * rh
+        - 0.000_001_99 * t * t * rh * rh;
+
+    if rh < 13. && (80. ..=112.).contains(&t) {
+        heat_index -= (13. - rh) / 4. * ((17. - (t - 95.).abs()) / 17.).sqrt();
+    } else if rh > 85. && (80. ..=87.).contains(&t) {
+        heat_index += (rh - 85.) / 10. * ((87. - t) / 5.);
+    }
+
+    Temperature::Fahrenheit(heat_index as f32).to_celsius()
+}
+
+/// Wind chill index of Environment Canada and the US National Weather Service (2001).
+/// Only defined for temperatures up to 10 °C and wind speeds from 4.8 km/h on.
+pub fn wind_chill(temperature: Temperature, wind_speed: Speed) -> Option<Temperature> {
+    let celsius = celsius(temperature);
+    let kilometers_per_hour = f32::from(wind_speed.to_kilometers_per_hour()) as f64;
+
+    if celsius > 10. || kilometers_per_hour < 4.8 {
+        return None;
+    }
+
+    let wind_factor = kilometers_per_hour.powf(0.16);
+    Some(Temperature::Celsius((13.12 + 0.6215 * celsius - 11.37 * wind_factor + 0.3965 * celsius * wind_factor) as f32))
+}
+
+/// Humidex of Environment Canada, with the vapour pressure calculated from the relative humidity rather than the dew point.
+pub fn humidex(temperature: Temperature, humidity: Percentage) -> Temperature {
+    let celsius = celsius(temperature);
+    let saturation_vapour_pressure = 6.11 * (5417.7530 * (1. / 273.16 - 1. / (273.15 + celsius))).exp();
+    let vapour_pressure = relative_humidity(humidity) * saturation_vapour_pressure;
+
+    Temperature::Celsius((celsius + 0.5555 * (vapour_pressure - 10.)) as f32)
+}
+
+/// Mass of water vapour per volume of air, from the ideal gas law.
+pub fn absolute_humidity(temperature: Temperature, humidity: Percentage) -> Concentration {
+    const SPECIFIC_GAS_CONSTANT_OF_WATER_VAPOUR: f64 = 461.5;
+
+    let celsius = celsius(temperature);
+    let vapour_pressure_pascal = relative_humidity(humidity) * saturation_vapour_pressure(celsius) * 100.;
+    let kilograms_per_cubic_meter = vapour_pressure_pascal / (SPECIFIC_GAS_CONSTANT_OF_WATER_VAPOUR * (celsius + 273.15));
+
+    Concentration::GramsPerCubicMeter((kilograms_per_cubic_meter * 1000.) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{Concentration, Percentage, Speed, Temperature};
+    use crate::data::derived::{absolute_humidity, apparent_temperature, dew_point, heat_index, humidex, wind_chill};
+
+    fn percentage(value: u8) -> Percentage {
+        Percentage::try_from(value).unwrap()
+    }
+
+    fn assert_close(actual: Temperature, expected: Temperature, tolerance: f32) {
+        let actual = actual.to_unit(expected.temperature_unit());
+        assert!((f32::from(actual) - f32::from(expected)).abs() <= tolerance, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn dew_points() {
+        // Psychrometric tables, rounded to 0.1 °C
+        let table = [(20., 50, 9.3), (30., 80, 26.2), (10., 90, 8.4), (0., 100, 0.), (35., 30, 14.8)];
+
+        for (celsius, humidity, expected) in table {
+            let dew_point = dew_point(Temperature::Celsius(celsius), percentage(humidity)).unwrap();
+            assert_close(dew_point, Temperature::Celsius(expected), 0.1);
+        }
+
+        assert!(dew_point(Temperature::Celsius(20.), percentage(0)).is_none());
+    }
+
+    #[test]
+    fn apparent_temperatures() {
+        let table = [(30., 70, 0., 35.8), (25., 50, 2., 24.8), (10., 80, 5., 5.7), (0., 50, 10., -10.)];
+
+        for (celsius, humidity, meters_per_second, expected) in table {
+            let apparent_temperature = apparent_temperature(Temperature::Celsius(celsius), percentage(humidity), Speed::MetersPerSecond(meters_per_second));
+            assert_close(apparent_temperature, Temperature::Celsius(expected), 0.05);
+        }
+    }
+
+    #[test]
+    fn heat_indices() {
+        // Heat index chart of the US National Weather Service, rounded to whole °F
+        let table = [(80., 40, 80.), (90., 50, 95.), (100., 40, 109.), (86., 90, 105.), (96., 65, 121.)];
+
+        for (fahrenheit, humidity, expected) in table {
+            let heat_index = heat_index(Temperature::Fahrenheit(fahrenheit), percentage(humidity));
+            assert_close(heat_index, Temperature::Fahrenheit(expected), 0.5);
+        }
+    }
+
+    #[test]
+    fn wind_chills() {
+        // Wind chill chart of Environment Canada, rounded to whole °C
+        let table = [(0., 10., -3.), (-10., 20., -18.), (-20., 30., -33.), (-30., 60., -50.), (-40., 80., -67.)];
+
+        for (celsius, kilometers_per_hour, expected) in table {
+            let wind_chill = wind_chill(Temperature::Celsius(celsius), Speed::KilometersPerHour(kilometers_per_hour)).unwrap();
+            assert_close(wind_chill, Temperature::Celsius(expected), 0.5);
+        }
+
+        assert!(wind_chill(Temperature::Celsius(15.), Speed::KilometersPerHour(30.)).is_none());
+        assert!(wind_chill(Temperature::Celsius(-10.), Speed::KilometersPerHour(3.)).is_none());
+    }
+
+    #[test]
+    fn humidices() {
+        // Humidex table of Environment Canada, rounded to whole °C
+        let table = [(30., 70, 41.), (25., 60, 30.), (35., 50, 46.), (20., 40, 20.)];
+
+        for (celsius, humidity, expected) in table {
+            let humidex = humidex(Temperature::Celsius(celsius), percentage(humidity));
+            assert_close(humidex, Temperature::Celsius(expected), 0.5);
+        }
+    }
+
+    #[test]
+    fn absolute_humidities() {
+        // Saturation vapour density of water, in g/m³
+        let table = [(0., 100, 4.85), (20., 100, 17.3), (30., 100, 30.38), (20., 50, 8.65)];
+
+        for (celsius, humidity, expected) in table {
+            let Concentration::GramsPerCubicMeter(actual) = absolute_humidity(Temperature::Celsius(celsius), percentage(humidity)) else {
+                panic!("Unexpected unit");
+            };
+
+            assert!((actual - expected).abs() <= 0.05, "{actual} != {expected}");
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Distances, bearings and positions on the surface of the earth.
+//! Bearings are given in degrees clockwise from true north, within [0, 360).
+
+use crate::data::{CompassDirection, Coordinates, Latitude, Length, Longitude};
+
+/// Mean radius of the IUGG, used for all spherical approximations.
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+// WGS 84 ellipsoid
+const SEMI_MAJOR_AXIS_METERS: f64 = 6_378_137.;
+const FLATTENING: f64 = 1. / 298.257_223_563;
+
+const VINCENTY_MAX_ITERATIONS: usize = 200;
+const VINCENTY_TOLERANCE: f64 = 1e-12;
+
+impl Coordinates {
+    fn to_radians(self) -> (f64, f64) {
+        (f64::from(self.latitude).to_radians(), f64::from(self.longitude).to_radians())
+    }
+
+    /// Longitudes are wrapped into [-180, 180), latitudes clamped to absorb rounding errors at the poles.
+    fn from_radians(latitude: f64, longitude: f64) -> Coordinates {
+        let latitude = latitude.to_degrees().clamp(-90., 90.);
+        let longitude = (longitude.to_degrees() + 540.).rem_euclid(360.) - 180.;
+
+        Coordinates {
+            latitude: Latitude::try_from(latitude).expect("The latitude is clamped into its range"),
+            longitude: Longitude::try_from(longitude).expect("The longitude is wrapped into its range"),
+        }
+    }
+
+    /// Great-circle distance on a spherical earth, accurate to about 0.5 %.
+    pub fn haversine_distance(&self, other: &Coordinates) -> Length {
+        let (latitude_1, longitude_1) = self.to_radians();
+        let (latitude_2, longitude_2) = other.to_radians();
+
+        let a = ((latitude_2 - latitude_1) / 2.).sin().powi(2)
+            + latitude_1.cos() * latitude_2.cos() * ((longitude_2 - longitude_1) / 2.).sin().powi(2);
+
+        Length::Meters((2. * EARTH_RADIUS_METERS * a.sqrt().min(1.).asin()) as f32)
+    }
+
+    /// Geodesic distance on the WGS 84 ellipsoid after Vincenty (1975).
+    /// The method itself is accurate to less than a millimetre, but the returned `f32` only resolves about 2 m at 20,000 km.
+    /// None for nearly antipodal points, for which the iteration does not converge.
+    pub fn vincenty_distance(&self, other: &Coordinates) -> Option<Length> {
+        let (latitude_1, longitude_1) = self.to_radians();
+        let (latitude_2, longitude_2) = other.to_radians();
+
+        let semi_minor_axis = SEMI_MAJOR_AXIS_METERS * (1. - FLATTENING);
+        let reduced_latitude_1 = ((1. - FLATTENING) * latitude_1.tan()).atan();
+        let reduced_latitude_2 = ((1. - FLATTENING) * latitude_2.tan()).atan();
+        let (sin_u1, cos_u1) = reduced_latitude_1.sin_cos();
+        let (sin_u2, cos_u2) = reduced_latitude_2.sin_cos();
+
+        let longitude_difference = longitude_2 - longitude_1;
+        let mut lambda = longitude_difference;
+
+        for _ in 0..VINCENTY_MAX_ITERATIONS {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+
+            let sin_sigma = ((cos_u2 * sin_lambda).powi(2) + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2)).sqrt();
+
+            if sin_sigma == 0. {
+                return Some(Length::Meters(0.));
+            }
+
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos_squared_alpha = 1. - sin_alpha.powi(2);
+
+            // Both points lie on the equator
+            let cos_2_sigma_m = if cos_squared_alpha == 0. { 0. } else { cos_sigma - 2. * sin_u1 * sin_u2 / cos_squared_alpha };
+
+            let c = FLATTENING / 16. * cos_squared_alpha * (4. + FLATTENING * (4. - 3. * cos_squared_alpha));
+            let previous_lambda = lambda;
+
+            lambda = longitude_difference + (1. - c) * FLATTENING * sin_alpha
+                * (sigma + c * sin_sigma * (cos_2_sigma_m + c * cos_sigma * (-1. + 2. * cos_2_sigma_m.powi(2))));
+
+            if (lambda - previous_lambda).abs() < VINCENTY_TOLERANCE {
+                let u_squared = cos_squared_alpha * (SEMI_MAJOR_AXIS_METERS.powi(2) - semi_minor_axis.powi(2)) / semi_minor_axis.powi(2);
+                let a = 1. + u_squared / 16384. * (4096. + u_squared * (-768. + u_squared * (320. - 175. * u_squared)));
+                let b = u_squared / 1024. * (256. + u_squared * (-128. + u_squared * (74. - 47. * u_squared)));
+
+                let delta_sigma = b * sin_sigma * (cos_2_sigma_m + b / 4. * (cos_sigma * (-1. + 2. * cos_2_sigma_m.powi(2))
+                    - b / 6. * cos_2_sigma_m * (-3. + 4. * sin_sigma.powi(2)) * (-3. + 4. * cos_2_sigma_m.powi(2))));
+
+                return Some(Length::Meters((semi_minor_axis * a * (sigma - delta_sigma)) as f32));
+            }
+        }
+
+        None
+    }
+
+    /// Bearing at the start of the great circle towards the other point. Zero if both points coincide.
+    pub fn initial_bearing(&self, other: &Coordinates) -> f64 {
+        let (latitude_1, longitude_1) = self.to_radians();
+        let (latitude_2, longitude_2) = other.to_radians();
+        let longitude_difference = longitude_2 - longitude_1;
+
+        let y = longitude_difference.sin() * latitude_2.cos();
+        let x = latitude_1.cos() * latitude_2.sin() - latitude_1.sin() * latitude_2.cos() * longitude_difference.cos();
+
+        y.atan2(x).to_degrees().rem_euclid(360.)
+    }
+
+    pub fn initial_compass_direction(&self, other: &Coordinates) -> CompassDirection {
+        CompassDirection::from_degrees(self.initial_bearing(other) as f32).expect("Bearings are always finite")
+    }
+
+    /// Point reached by following the great circle from here for the given distance and initial bearing.
+    pub fn destination(&self, distance: Length, bearing: f64) -> Coordinates {
+        let (latitude, longitude) = self.to_radians();
+        let angular_distance = f32::from(distance.to_meters()) as f64 / EARTH_RADIUS_METERS;
+        let bearing = bearing.to_radians();
+
+        let destination_latitude = (latitude.sin() * angular_distance.cos()
+            + latitude.cos() * angular_distance.sin() * bearing.cos()).clamp(-1., 1.).asin();
+
+        let destination_longitude = longitude + (bearing.sin() * angular_distance.sin() * latitude.cos())
+            .atan2(angular_distance.cos() - latitude.sin() * destination_latitude.sin());
+
+        Coordinates::from_radians(destination_latitude, destination_longitude)
+    }
+
+    /// Halfway point along the great circle between both points.
+    pub fn midpoint(&self, other: &Coordinates) -> Coordinates {
+        let (latitude_1, longitude_1) = self.to_radians();
+        let (latitude_2, longitude_2) = other.to_radians();
+        let longitude_difference = longitude_2 - longitude_1;
+
+        let b_x = latitude_2.cos() * longitude_difference.cos();
+        let b_y = latitude_2.cos() * longitude_difference.sin();
+
+        let latitude = (latitude_1.sin() + latitude_2.sin()).atan2(((latitude_1.cos() + b_x).powi(2) + b_y.powi(2)).sqrt());
+        let longitude = longitude_1 + b_y.atan2(latitude_1.cos() + b_x);
+
+        Coordinates::from_radians(latitude, longitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{CompassDirection, Coordinates, Length};
+    use crate::test_util::coordinates;
+
+    fn kilometers(length: Length) -> f32 {
+        f32::from(length.to_kilometers())
+    }
+
+    fn assert_close_to(actual: Coordinates, expected: Coordinates) {
+        assert!(f32::from(actual.haversine_distance(&expected)) < 1., "{actual} != {expected}");
+    }
+
+    #[test]
+    fn haversine_distances() {
+        let berlin = coordinates(52.5200, 13.4050);
+        let paris = coordinates(48.8566, 2.3522);
+
+        assert!((kilometers(berlin.haversine_distance(&paris)) - 877.5).abs() < 1.);
+        assert_eq!(berlin.haversine_distance(&berlin), Length::Meters(0.));
+        assert!((kilometers(coordinates(0., 0.).haversine_distance(&coordinates(0., 180.))) - 20_015.1).abs() < 1.);
+    }
+
+    #[test]
+    fn vincenty_distances() {
+        // Flinders Peak to Buninyong, the example of Vincenty (1975)
+        let flinders_peak = coordinates(-37.951_033, 144.424_868);
+        let buninyong = coordinates(-37.652_821, 143.926_496);
+        let distance = f32::from(flinders_peak.vincenty_distance(&buninyong).unwrap());
+        assert!((distance - 54_972.27).abs() < 0.5, "{distance}");
+
+        let equator_distance = f32::from(coordinates(0., 0.).vincenty_distance(&coordinates(0., 1.)).unwrap());
+        assert!((equator_distance - 111_319.49).abs() < 0.5, "{equator_distance}");
+
+        assert_eq!(flinders_peak.vincenty_distance(&flinders_peak), Some(Length::Meters(0.)));
+        assert_eq!(coordinates(0., 0.).vincenty_distance(&coordinates(0.5, 179.7)), None);
+    }
+
+    #[test]
+    fn initial_bearings() {
+        let origin = coordinates(0., 0.);
+
+        assert!((origin.initial_bearing(&coordinates(1., 0.)) - 0.).abs() < 1e-9);
+        assert!((origin.initial_bearing(&coordinates(0., 1.)) - 90.).abs() < 1e-9);
+        assert!((origin.initial_bearing(&coordinates(-1., 0.)) - 180.).abs() < 1e-9);
+        assert!((origin.initial_bearing(&coordinates(0., -1.)) - 270.).abs() < 1e-9);
+
+        let berlin = coordinates(52.5200, 13.4050);
+        let paris = coordinates(48.8566, 2.3522);
+        assert!((berlin.initial_bearing(&paris) - 246.7).abs() < 0.1);
+        assert_eq!(berlin.initial_compass_direction(&paris), CompassDirection::SW);
+        assert_eq!(paris.initial_compass_direction(&berlin), CompassDirection::NE);
+    }
+
+    #[test]
+    fn destinations() {
+        let berlin = coordinates(52.5200, 13.4050);
+        let paris = coordinates(48.8566, 2.3522);
+
+        let destination = berlin.destination(berlin.haversine_distance(&paris), berlin.initial_bearing(&paris));
+        assert_close_to(destination, paris);
+
+        assert_close_to(coordinates(0., 179.5).destination(Length::Kilometers(111.195), 90.), coordinates(0., -179.5));
+        assert_close_to(coordinates(89.5, 0.).destination(Length::Kilometers(111.195), 0.), coordinates(89.5, 180.));
+    }
+
+    #[test]
+    fn midpoints() {
+        assert_close_to(coordinates(0., 0.).midpoint(&coordinates(0., 90.)), coordinates(0., 45.));
+        assert_close_to(coordinates(10., 179.).midpoint(&coordinates(10., -179.)), coordinates(10.001_5, -180.));
+
+        let berlin = coordinates(52.5200, 13.4050);
+        let paris = coordinates(48.8566, 2.3522);
+        let midpoint = berlin.midpoint(&paris);
+
+        let to_berlin = f32::from(midpoint.haversine_distance(&berlin));
+        let to_paris = f32::from(midpoint.haversine_distance(&paris));
+        assert!((to_berlin - to_paris).abs() < 1.);
+    }
+}
@@ -0,0 +1,153 @@
+//! Geohashes, which interleave the bits of the longitude and latitude and encode them in base 32, e.g. `u33dc0c`.
+
+use crate::data::Coordinates;
+
+const ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+const BITS_PER_CHARACTER: usize = 5;
+
+/// 12 characters describe cells of a few centimetres, which is finer than the coordinates themselves.
+pub const MAX_LENGTH: usize = 12;
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("A geohash must have between 1 and {MAX_LENGTH} characters, but it has {0}")]
+    InvalidLength(usize),
+
+    #[error("'{0}' is not a geohash character")]
+    InvalidCharacter(char),
+}
+
+/// Bounds of a cell, halved by every bit.
+struct Cell {
+    latitude: (f64, f64),
+    longitude: (f64, f64),
+}
+
+impl Cell {
+    fn new() -> Self {
+        Cell { latitude: (-90., 90.), longitude: (-180., 180.) }
+    }
+
+    /// Bits alternate between longitude and latitude, starting with the longitude.
+    fn bounds(&mut self, bit_index: usize) -> &mut (f64, f64) {
+        if bit_index.is_multiple_of(2) { &mut self.longitude } else { &mut self.latitude }
+    }
+}
+
+impl Coordinates {
+    /// The length is clamped into [1, MAX_LENGTH], 7 characters describe cells of about 150 m.
+    pub fn to_geohash(&self, length: usize) -> String {
+        let latitude = f64::from(self.latitude);
+        let longitude = f64::from(self.longitude);
+        let mut cell = Cell::new();
+
+        (0..length.clamp(1, MAX_LENGTH)).map(|character_index| {
+            let mut index = 0;
+
+            for bit in 0..BITS_PER_CHARACTER {
+                let bit_index = character_index * BITS_PER_CHARACTER + bit;
+                let value = if bit_index.is_multiple_of(2) { longitude } else { latitude };
+                let bounds = cell.bounds(bit_index);
+                let middle = (bounds.0 + bounds.1) / 2.;
+
+                index <<= 1;
+
+                // The upper limits 90° and 180° belong to the last cell
+                if value >= middle {
+                    index |= 1;
+                    bounds.0 = middle;
+                } else {
+                    bounds.1 = middle;
+                }
+            }
+
+            ALPHABET[index] as char
+        }).collect()
+    }
+
+    /// Centre of the cell described by the geohash, ignoring case.
+    pub fn from_geohash(geohash: &str) -> Result<Coordinates, Error> {
+        let length = geohash.chars().count();
+
+        if !(1..=MAX_LENGTH).contains(&length) {
+            return Err(Error::InvalidLength(length));
+        }
+
+        let mut cell = Cell::new();
+
+        for (character_index, character) in geohash.chars().enumerate() {
+            let index = ALPHABET.iter()
+                .position(|&valid_character| char::from(valid_character) == character.to_ascii_lowercase())
+                .ok_or(Error::InvalidCharacter(character))?;
+
+            for bit in 0..BITS_PER_CHARACTER {
+                let bounds = cell.bounds(character_index * BITS_PER_CHARACTER + bit);
+                let middle = (bounds.0 + bounds.1) / 2.;
+
+                if index >> (BITS_PER_CHARACTER - 1 - bit) & 1 == 1 {
+                    bounds.0 = middle;
+                } else {
+                    bounds.1 = middle;
+                }
+            }
+        }
+
+        Ok(Coordinates::cell_centre(cell.latitude.0, cell.longitude.0, cell.latitude.1, cell.longitude.1))
+    }
+
+    /// Whether the text only consists of geohash characters and contains a letter, so that plain numbers are not taken for geohashes.
+    pub(crate) fn looks_like_geohash(text: &str) -> bool {
+        text.chars().any(|character| character.is_ascii_alphabetic())
+            && text.chars().all(|character| character.is_ascii() && ALPHABET.contains(&(character.to_ascii_lowercase() as u8)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::geohash::{Error, MAX_LENGTH};
+    use crate::data::Coordinates;
+    use crate::test_util::coordinates;
+
+    #[test]
+    fn encoding() {
+        assert_eq!(coordinates(57.64911, 10.40744).to_geohash(11), "u4pruydqqvj");
+        assert_eq!(coordinates(42.605, -5.603).to_geohash(5), "ezs42");
+        assert_eq!(coordinates(52.52, 13.405).to_geohash(7), "u33dc0c");
+        assert_eq!(coordinates(52.52, 13.405).to_geohash(0), "u");
+        assert_eq!(coordinates(52.52, 13.405).to_geohash(20).len(), MAX_LENGTH);
+    }
+
+    #[test]
+    fn decoding() {
+        let decoded = Coordinates::from_geohash("EZS42").unwrap();
+        assert!((f64::from(decoded.latitude) - 42.605).abs() < 0.03);
+        assert!((f64::from(decoded.longitude) - -5.603).abs() < 0.03);
+
+        assert!(matches!(Coordinates::from_geohash(""), Err(Error::InvalidLength(0))));
+        assert!(matches!(Coordinates::from_geohash("u33dc0cu33dc0"), Err(Error::InvalidLength(13))));
+        assert!(matches!(Coordinates::from_geohash("u33a"), Err(Error::InvalidCharacter('a'))));
+    }
+
+    #[test]
+    fn round_trips_at_range_limits() {
+        let limits = [(-90., -180.), (-90., 180.), (90., -180.), (90., 180.), (0., 0.), (52.52, 13.405)];
+
+        for (latitude, longitude) in limits {
+            let original = coordinates(latitude, longitude);
+
+            // Cells of 12 characters are finer than micro-degrees
+            for length in 1..MAX_LENGTH {
+                let geohash = original.to_geohash(length);
+                let decoded = Coordinates::from_geohash(&geohash).unwrap();
+
+                let bits = 5 * length as i32;
+                let height = 180. / 2_f64.powi(bits / 2);
+                let width = 360. / 2_f64.powi(bits - bits / 2);
+
+                assert!((f64::from(decoded.latitude) - latitude).abs() <= height / 2. + 1e-6, "{geohash}: {decoded}");
+                assert!((f64::from(decoded.longitude) - longitude).abs() <= width / 2. + 1e-6, "{geohash}: {decoded}");
+                assert_eq!(decoded.to_geohash(length), geohash);
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt::{self, Display, Formatter};
+use crate::data::{Measurement, UnitSystem};
+
+const METERS_PER_KILOMETER: f64 = 1000.;
+const METERS_PER_FOOT: f64 = 0.3048;
+const METERS_PER_MILE: f64 = 1609.344;
+const METERS_PER_NAUTICAL_MILE: f64 = 1852.;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Length {
+    Meters(f32),
+    Kilometers(f32),
+    Feet(f32),
+    Miles(f32),
+    NauticalMiles(f32),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LengthUnit {
+    Meters,
+    Kilometers,
+    Feet,
+    Miles,
+    NauticalMiles,
+}
+
+impl LengthUnit {
+    pub const ALL: [LengthUnit; 5] = [LengthUnit::Meters, LengthUnit::Kilometers, LengthUnit::Feet, LengthUnit::Miles, LengthUnit::NauticalMiles];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            LengthUnit::Meters => "m",
+            LengthUnit::Kilometers => "km",
+            LengthUnit::Feet => "ft",
+            LengthUnit::Miles => "mi",
+            LengthUnit::NauticalMiles => "NM",
+        }
+    }
+
+    /// Whether the unit is meant for distances rather than heights.
+    pub fn is_distance_unit(&self) -> bool {
+        matches!(self, LengthUnit::Kilometers | LengthUnit::Miles | LengthUnit::NauticalMiles)
+    }
+}
+
+impl Length {
+    pub fn length_unit(&self) -> LengthUnit {
+        match self {
+            Length::Meters(_) => LengthUnit::Meters,
+            Length::Kilometers(_) => LengthUnit::Kilometers,
+            Length::Feet(_) => LengthUnit::Feet,
+            Length::Miles(_) => LengthUnit::Miles,
+            Length::NauticalMiles(_) => LengthUnit::NauticalMiles,
+        }
+    }
+
+    fn meters(&self) -> f64 {
+        match *self {
+            Length::Meters(value) => value as f64,
+            Length::Kilometers(value) => value as f64 * METERS_PER_KILOMETER,
+            Length::Feet(value) => value as f64 * METERS_PER_FOOT,
+            Length::Miles(value) => value as f64 * METERS_PER_MILE,
+            Length::NauticalMiles(value) => value as f64 * METERS_PER_NAUTICAL_MILE,
+        }
+    }
+
+    pub fn to_meters(self) -> Length {
+        match self {
+            Length::Meters(_) => self,
+            _ => Length::Meters(self.meters() as f32),
+        }
+    }
+
+    pub fn to_kilometers(self) -> Length {
+        match self {
+            Length::Kilometers(_) => self,
+            _ => Length::Kilometers((self.meters() / METERS_PER_KILOMETER) as f32),
+        }
+    }
+
+    pub fn to_feet(self) -> Length {
+        match self {
+            Length::Feet(_) => self,
+            _ => Length::Feet((self.meters() / METERS_PER_FOOT) as f32),
+        }
+    }
+
+    pub fn to_miles(self) -> Length {
+        match self {
+            Length::Miles(_) => self,
+            _ => Length::Miles((self.meters() / METERS_PER_MILE) as f32),
+        }
+    }
+
+    pub fn to_nautical_miles(self) -> Length {
+        match self {
+            Length::NauticalMiles(_) => self,
+            _ => Length::NauticalMiles((self.meters() / METERS_PER_NAUTICAL_MILE) as f32),
+        }
+    }
+
+    pub fn to_unit(self, unit: LengthUnit) -> Length {
+        match unit {
+            LengthUnit::Meters => self.to_meters(),
+            LengthUnit::Kilometers => self.to_kilometers(),
+            LengthUnit::Feet => self.to_feet(),
+            LengthUnit::Miles => self.to_miles(),
+            LengthUnit::NauticalMiles => self.to_nautical_miles(),
+        }
+    }
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&f32::from(*self), f)?;
+        write!(f, " {}", self.unit())
+    }
+}
+
+impl From<Length> for f32 {
+    fn from(value: Length) -> Self {
+        match value {
+            Length::Meters(value) => value,
+            Length::Kilometers(value) => value,
+            Length::Feet(value) => value,
+            Length::Miles(value) => value,
+            Length::NauticalMiles(value) => value,
+        }
+    }
+}
+
+impl Measurement for Length {
+    fn unit(&self) -> &'static str {
+        self.length_unit().symbol()
+    }
+
+    fn decimal_places(&self) -> usize {
+        match self {
+            Length::Meters(_) | Length::Feet(_) => 0,
+            Length::Kilometers(_) | Length::Miles(_) | Length::NauticalMiles(_) => 1,
+        }
+    }
+
+    /// Distances follow the distance unit of the system, heights like wave heights and elevations its length unit.
+    fn to_unit_system(self, unit_system: UnitSystem) -> Self {
+        if self.length_unit().is_distance_unit() {
+            self.to_unit(unit_system.distance)
+        } else {
+            self.to_unit(unit_system.length)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{Length, LengthUnit, Measurement, UnitSystem};
+
+    fn assert_close(actual: Length, expected: Length) {
+        assert_eq!(actual.length_unit(), expected.length_unit());
+        assert!((f32::from(actual) - f32::from(expected)).abs() < 1e-3, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn reference_points() {
+        assert_close(Length::Kilometers(1.5).to_meters(), Length::Meters(1500.));
+        assert_close(Length::Feet(1.).to_meters(), Length::Meters(0.3048));
+        assert_close(Length::Meters(1.).to_feet(), Length::Feet(3.28084));
+        assert_close(Length::Feet(3280.84).to_kilometers(), Length::Kilometers(1.));
+        assert_close(Length::Miles(1.).to_kilometers(), Length::Kilometers(1.609344));
+        assert_close(Length::NauticalMiles(1.).to_meters(), Length::Meters(1852.));
+        assert_close(Length::Miles(1.).to_feet(), Length::Feet(5280.));
+    }
+
+    #[test]
+    fn round_trips() {
+        for tenths in 0..=2000 {
+            let length = Length::Meters(tenths as f32 / 10.);
+
+            for unit in LengthUnit::ALL {
+                assert_close(length.to_unit(unit).to_meters(), length);
+            }
+        }
+    }
+
+    #[test]
+    fn unit_systems() {
+        assert_close(Length::Meters(1.42).to_unit_system(UnitSystem::IMPERIAL), Length::Feet(4.65879));
+        assert_close(Length::Feet(10.).to_unit_system(UnitSystem::METRIC), Length::Meters(3.048));
+        assert_close(Length::Kilometers(2.3).to_unit_system(UnitSystem::IMPERIAL), Length::Miles(1.42915));
+        assert_close(Length::NauticalMiles(1.).to_unit_system(UnitSystem::METRIC), Length::Kilometers(1.852));
+    }
+}
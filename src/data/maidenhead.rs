@@ -0,0 +1,142 @@
+//! Maidenhead locators as used by radio amateurs, e.g. `JO62qm`. Each pair of characters divides the cell
+//! of the previous pair, giving the longitude first: fields of 20° × 10° (`A`–`R`), squares of 2° × 1° (`0`–`9`),
+//! subsquares of 5' × 2.5' (`a`–`x`), extended squares (`0`–`9`) and extended subsquares (`a`–`x`).
+
+use crate::data::Coordinates;
+
+const DIVISIONS: [u32; MAX_PAIRS] = [18, 10, 24, 10, 24];
+
+pub const MAX_PAIRS: usize = 5;
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("A Maidenhead locator must have 2, 4, 6, 8 or 10 characters, but it has {0}")]
+    InvalidLength(usize),
+
+    #[error("'{0}' is not valid at position {1} of a Maidenhead locator")]
+    InvalidCharacter(char, usize),
+}
+
+/// Squares are numbered by digits, fields in upper and subsquares in lower case letters.
+fn character(pair: usize, index: u32) -> char {
+    if pair % 2 == 1 {
+        char::from_digit(index, 10).expect("Squares are divided into ten")
+    } else if pair == 0 {
+        char::from(b'A' + index as u8)
+    } else {
+        char::from(b'a' + index as u8)
+    }
+}
+
+/// Letters are accepted in either case.
+fn index(pair: usize, character: char) -> Option<u32> {
+    let index = if pair % 2 == 1 {
+        character.to_digit(10)?
+    } else {
+        (character.to_ascii_uppercase() as u32).checked_sub('A' as u32)?
+    };
+
+    (index < DIVISIONS[pair]).then_some(index)
+}
+
+impl Coordinates {
+    /// The number of pairs is clamped into [1, MAX_PAIRS], 3 pairs describe subsquares of about 5 km.
+    pub fn to_maidenhead(&self, pairs: usize) -> String {
+        // Position within the current cell, as a fraction of its size
+        let mut longitude = (f64::from(self.longitude) + 180.) / 360.;
+        let mut latitude = (f64::from(self.latitude) + 90.) / 180.;
+
+        let mut locator = String::with_capacity(2 * MAX_PAIRS);
+
+        for (pair, divisions) in DIVISIONS.into_iter().take(pairs.clamp(1, MAX_PAIRS)).enumerate() {
+            for fraction in [&mut longitude, &mut latitude] {
+                let scaled = *fraction * f64::from(divisions);
+
+                // The upper limits 90° and 180° belong to the last cell
+                let index = (scaled as u32).min(divisions - 1);
+
+                *fraction = scaled - f64::from(index);
+                locator.push(character(pair, index));
+            }
+        }
+
+        locator
+    }
+
+    /// Centre of the cell described by the locator.
+    pub fn from_maidenhead(locator: &str) -> Result<Coordinates, Error> {
+        let characters = locator.chars().collect::<Vec<_>>();
+
+        if characters.len() % 2 == 1 || !(1..=MAX_PAIRS).contains(&(characters.len() / 2)) {
+            return Err(Error::InvalidLength(characters.len()));
+        }
+
+        let (mut south, mut west) = (-90., -180.);
+        let (mut height, mut width) = (180., 360.);
+
+        for (pair, (divisions, characters)) in DIVISIONS.into_iter().zip(characters.chunks(2)).enumerate() {
+            let index = |position: usize| {
+                index(pair, characters[position]).ok_or(Error::InvalidCharacter(characters[position], 2 * pair + position))
+            };
+
+            width /= f64::from(divisions);
+            height /= f64::from(divisions);
+            west += f64::from(index(0)?) * width;
+            south += f64::from(index(1)?) * height;
+        }
+
+        Ok(Coordinates::cell_centre(south, west, south + height, west + width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::maidenhead::{Error, MAX_PAIRS};
+    use crate::data::Coordinates;
+    use crate::test_util::coordinates;
+
+    #[test]
+    fn encoding() {
+        assert_eq!(coordinates(52.52, 13.405).to_maidenhead(3), "JO62qm");
+        assert_eq!(coordinates(48.146, 11.608).to_maidenhead(3), "JN58td");
+        assert_eq!(coordinates(-33.8688, 151.2093).to_maidenhead(2), "QF56");
+        assert_eq!(coordinates(52.52, 13.405).to_maidenhead(0), "JO");
+        assert_eq!(coordinates(52.52, 13.405).to_maidenhead(9).len(), 2 * MAX_PAIRS);
+    }
+
+    #[test]
+    fn decoding() {
+        let decoded = Coordinates::from_maidenhead("jo62QM").unwrap();
+        assert!((f64::from(decoded.latitude) - 52.52).abs() < 2.5 / 60.);
+        assert!((f64::from(decoded.longitude) - 13.405).abs() < 5. / 60.);
+
+        assert!(matches!(Coordinates::from_maidenhead(""), Err(Error::InvalidLength(0))));
+        assert!(matches!(Coordinates::from_maidenhead("JO6"), Err(Error::InvalidLength(3))));
+        assert!(matches!(Coordinates::from_maidenhead("JS62"), Err(Error::InvalidCharacter('S', 1))));
+        assert!(matches!(Coordinates::from_maidenhead("JO6x"), Err(Error::InvalidCharacter('x', 3))));
+        assert!(matches!(Coordinates::from_maidenhead("JO62qz"), Err(Error::InvalidCharacter('z', 5))));
+    }
+
+    #[test]
+    fn round_trips_at_range_limits() {
+        let limits = [(-90., -180.), (-90., 180.), (90., -180.), (90., 180.), (0., 0.), (52.52, 13.405)];
+
+        for (latitude, longitude) in limits {
+            let original = coordinates(latitude, longitude);
+            let (mut height, mut width) = (180., 360.);
+
+            for pairs in 1..=MAX_PAIRS {
+                let divisions = f64::from([18, 10, 24, 10, 24][pairs - 1]);
+                height /= divisions;
+                width /= divisions;
+
+                let locator = original.to_maidenhead(pairs);
+                let decoded = Coordinates::from_maidenhead(&locator).unwrap();
+
+                assert!((f64::from(decoded.latitude) - latitude).abs() <= height / 2. + 1e-6, "{locator}: {decoded}");
+                assert!((f64::from(decoded.longitude) - longitude).abs() <= width / 2. + 1e-6, "{locator}: {decoded}");
+                assert_eq!(decoded.to_maidenhead(pairs), locator);
+            }
+        }
+    }
+}
@@ -1,5 +1,17 @@
 use std::fmt::{Debug, Display};
+use crate::data::UnitSystem;
 
 pub trait Measurement: Copy + Debug + Display + Into<f32> + PartialEq {
     fn unit(&self) -> &'static str;
+
+    /// Number of decimal places that is meaningful when displaying this measurement.
+    fn decimal_places(&self) -> usize {
+        1
+    }
+
+    /// Converts the measurement into the unit the given system uses for its quantity.
+    /// Measurements without alternative units are returned unchanged.
+    fn to_unit_system(self, _unit_system: UnitSystem) -> Self {
+        self
+    }
 }
@@ -1,23 +1,60 @@
+pub mod air_quality_index;
+pub use air_quality_index::{AirQualityIndex, HealthBand};
+
+pub mod bearing;
+pub use bearing::Bearing;
+
 pub mod compass_direction;
-pub use compass_direction::CompassDirection;
+pub use compass_direction::{CompassDirection, CompassResolution};
+
+pub mod concentration;
+pub use concentration::Concentration;
 
 pub mod coordinates;
 pub use coordinates::{Coordinate, Coordinates, Latitude, Longitude};
 
+pub mod date;
+pub use date::{Date, DateRange, DateTime};
+
+pub mod derived;
+
+pub mod geodesy;
+
+pub mod geohash;
+
+pub mod length;
+pub use length::{Length, LengthUnit};
+
+pub mod maidenhead;
+
 pub mod measurement;
 pub use measurement::Measurement;
 
 pub mod percentage;
 pub use percentage::Percentage;
 
+pub mod period;
+pub use period::Period;
+
+pub mod plus_code;
+
+pub mod pollen;
+pub use pollen::{PollenCount, PollenSpecies};
+
+pub mod precipitation;
+pub use precipitation::{Precipitation, PrecipitationUnit};
+
 pub mod pressure;
-pub use pressure::Pressure;
+pub use pressure::{Pressure, PressureUnit};
 
 pub mod speed;
-pub use speed::Speed;
+pub use speed::{Speed, SpeedUnit};
 
 pub mod temperature;
-pub use temperature::Temperature;
+pub use temperature::{Temperature, TemperatureUnit};
+
+pub mod unit_system;
+pub use unit_system::UnitSystem;
 
 pub mod weather;
 pub use weather::{Weather, WeatherRegistry};
@@ -1,7 +1,9 @@
 use std::fmt::{self, Display, Formatter};
+use serde::{Deserialize, Serialize};
 use crate::data::Measurement;
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(try_from = "u8", into = "u8")]
 pub struct Percentage(u8);
 
 impl TryFrom<u8> for Percentage {
@@ -16,6 +18,12 @@ impl TryFrom<u8> for Percentage {
     }
 }
 
+impl From<Percentage> for u8 {
+    fn from(value: Percentage) -> Self {
+        value.0
+    }
+}
+
 impl From<Percentage> for f32 {
     fn from(value: Percentage) -> Self {
         (value.0 as f32) / 100.
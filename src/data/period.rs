@@ -0,0 +1,32 @@
+use std::fmt::{self, Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::data::Measurement;
+
+/// Time between two recurring events, e.g. successive wave crests.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Period {
+    Seconds(f32),
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&f32::from(*self), f)?;
+        write!(f, " {}", self.unit())
+    }
+}
+
+impl From<Period> for f32 {
+    fn from(value: Period) -> Self {
+        match value {
+            Period::Seconds(value) => value,
+        }
+    }
+}
+
+impl Measurement for Period {
+    fn unit(&self) -> &'static str {
+        match self {
+            Period::Seconds(_) => "s",
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Open Location Codes, better known as Plus Codes, e.g. `9F4MGCC4+22`. The first ten digits encode pairs of
+//! latitude and longitude in base 20, each further digit divides the cell into a grid of 5 rows and 4 columns.
+//! Only full codes are supported, as short codes need a reference location.
+
+use crate::data::Coordinates;
+
+const ALPHABET: &[u8; 20] = b"23456789CFGHJMPQRVWX";
+const SEPARATOR: char = '+';
+const SEPARATOR_POSITION: usize = 8;
+const PADDING: char = '0';
+
+const PAIR_LENGTH: usize = 10;
+const GRID_LENGTH: usize = MAX_LENGTH - PAIR_LENGTH;
+const GRID_ROWS: i64 = 5;
+const GRID_COLUMNS: i64 = 4;
+
+/// Steps per degree of the last pair and of the last grid digit.
+const PAIR_PRECISION: i64 = 8000;
+const FINAL_LATITUDE_PRECISION: i64 = PAIR_PRECISION * GRID_ROWS.pow(GRID_LENGTH as u32);
+const FINAL_LONGITUDE_PRECISION: i64 = PAIR_PRECISION * GRID_COLUMNS.pow(GRID_LENGTH as u32);
+
+pub const MIN_LENGTH: usize = 2;
+pub const MAX_LENGTH: usize = 15;
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("\"{0}\" is not a valid Plus Code")]
+    Invalid(String),
+
+    #[error("\"{0}\" is a short Plus Code, which needs a reference location, please enter the full code")]
+    ShortCode(String),
+}
+
+fn digit_value(digit: char) -> Option<i64> {
+    ALPHABET.iter().position(|&valid_digit| char::from(valid_digit) == digit).map(|value| value as i64)
+}
+
+/// Checks the format of an upper case code, padding is only allowed for codes of less than eight digits.
+fn validate(code: &str) -> Result<(), Error> {
+    let invalid = || Error::Invalid(code.to_owned());
+
+    let separator = code.find(SEPARATOR).ok_or_else(invalid)?;
+
+    if code.rfind(SEPARATOR) != Some(separator) || separator % 2 == 1 || separator > SEPARATOR_POSITION {
+        return Err(invalid());
+    }
+
+    if !code.chars().all(|character| character == SEPARATOR || character == PADDING || digit_value(character).is_some()) {
+        return Err(invalid());
+    }
+
+    if separator < SEPARATOR_POSITION {
+        return Err(Error::ShortCode(code.to_owned()));
+    }
+
+    if let (Some(padding_start), Some(padding_end)) = (code.find(PADDING), code.rfind(PADDING)) {
+        let is_contiguous = code[padding_start..=padding_end].chars().all(|character| character == PADDING);
+
+        if padding_start == 0 || padding_start % 2 == 1 || padding_end + 1 != separator || !is_contiguous || code.len() > separator + 1 {
+            return Err(invalid());
+        }
+    }
+
+    if code.len() == separator + 2 {
+        return Err(invalid());
+    }
+
+    // The first pair must not exceed 90° and 180°
+    let mut characters = code.chars();
+    let first_latitude_digit = characters.next().and_then(digit_value).ok_or_else(invalid)?;
+    let first_longitude_digit = characters.next().and_then(digit_value).ok_or_else(invalid)?;
+
+    if first_latitude_digit >= 9 || first_longitude_digit >= 18 {
+        return Err(invalid());
+    }
+
+    Ok(())
+}
+
+impl Coordinates {
+    /// The length in digits is clamped into [MIN_LENGTH, MAX_LENGTH] and rounded down to an even number below 10.
+    /// 10 digits describe cells of about 14 m.
+    pub fn to_plus_code(&self, length: usize) -> String {
+        let length = length.clamp(MIN_LENGTH, MAX_LENGTH);
+        let length = if length < PAIR_LENGTH { length - length % 2 } else { length };
+
+        // Integer steps of the finest grid, rounded first to avoid floating point artefacts. The north pole belongs
+        // to the last row and 180° wraps around to -180°.
+        let steps = |value: f64, precision: i64| ((value * precision as f64 * 1e6).round() / 1e6).floor() as i64;
+        let mut latitude = steps(f64::from(self.latitude) + 90., FINAL_LATITUDE_PRECISION).clamp(0, 180 * FINAL_LATITUDE_PRECISION - 1);
+        let mut longitude = steps(f64::from(self.longitude) + 180., FINAL_LONGITUDE_PRECISION).rem_euclid(360 * FINAL_LONGITUDE_PRECISION);
+
+        let mut digits = [0; MAX_LENGTH];
+
+        for grid_digit in (PAIR_LENGTH..MAX_LENGTH).rev() {
+            digits[grid_digit] = ALPHABET[(latitude % GRID_ROWS * GRID_COLUMNS + longitude % GRID_COLUMNS) as usize];
+            latitude /= GRID_ROWS;
+            longitude /= GRID_COLUMNS;
+        }
+
+        for pair in (0..PAIR_LENGTH / 2).rev() {
+            digits[2 * pair] = ALPHABET[(latitude % 20) as usize];
+            digits[2 * pair + 1] = ALPHABET[(longitude % 20) as usize];
+            latitude /= 20;
+            longitude /= 20;
+        }
+
+        let mut code = digits[..length].iter().map(|&digit| char::from(digit)).collect::<String>();
+
+        while code.len() < SEPARATOR_POSITION {
+            code.push(PADDING);
+        }
+
+        code.insert(SEPARATOR_POSITION, SEPARATOR);
+        code
+    }
+
+    /// Centre of the cell described by a full code, ignoring case. Digits beyond MAX_LENGTH are ignored.
+    pub fn from_plus_code(code: &str) -> Result<Coordinates, Error> {
+        let code = code.to_ascii_uppercase();
+        validate(&code)?;
+
+        let digits = code.chars().filter_map(digit_value).take(MAX_LENGTH).collect::<Vec<_>>();
+
+        // In steps of the finest grid, the first pair counts in steps of 20°
+        let (mut south, mut west) = (0, 0);
+        let (mut height, mut width) = (400 * FINAL_LATITUDE_PRECISION, 400 * FINAL_LONGITUDE_PRECISION);
+
+        for pair in digits.chunks(2).take(PAIR_LENGTH / 2) {
+            height /= 20;
+            width /= 20;
+            south += pair[0] * height;
+            west += pair[1] * width;
+        }
+
+        for &digit in digits.iter().skip(PAIR_LENGTH) {
+            height /= GRID_ROWS;
+            width /= GRID_COLUMNS;
+            south += digit / GRID_COLUMNS * height;
+            west += digit % GRID_COLUMNS * width;
+        }
+
+        let latitude = |steps: i64| steps as f64 / FINAL_LATITUDE_PRECISION as f64 - 90.;
+        let longitude = |steps: i64| steps as f64 / FINAL_LONGITUDE_PRECISION as f64 - 180.;
+
+        Ok(Coordinates::cell_centre(latitude(south), longitude(west), latitude(south + height), longitude(west + width)))
+    }
+
+    /// Whether the text is made of digits and a separator, so that it is meant to be a Plus Code rather than signed numbers.
+    pub(crate) fn looks_like_plus_code(text: &str) -> bool {
+        text.find(SEPARATOR).is_some_and(|separator| separator > 0)
+            && text.chars().all(|character| character.is_ascii_alphanumeric() || character == SEPARATOR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::plus_code::{Error, MAX_LENGTH, MIN_LENGTH};
+    use crate::data::Coordinates;
+    use crate::test_util::coordinates;
+
+    /// Size of the cells in degrees, as latitude and longitude.
+    fn cell_size(length: usize) -> (f64, f64) {
+        if length <= 10 {
+            let size = 20. / 20_f64.powi(length as i32 / 2 - 1);
+            (size, size)
+        } else {
+            let grid_digits = length as i32 - 10;
+            (1. / 8000. / 5_f64.powi(grid_digits), 1. / 8000. / 4_f64.powi(grid_digits))
+        }
+    }
+
+    #[test]
+    fn encoding() {
+        assert_eq!(coordinates(47.36559, 8.524997).to_plus_code(10), "8FVC9G8F+6X");
+        assert_eq!(coordinates(47.36559, 8.524997).to_plus_code(11), "8FVC9G8F+6XQ");
+        assert_eq!(coordinates(52.52, 13.405).to_plus_code(10), "9F4MGCC4+22");
+        assert_eq!(coordinates(52.52, 13.405).to_plus_code(5), "9F4M0000+");
+        assert_eq!(coordinates(52.52, 13.405).to_plus_code(0), "9F000000+");
+        assert_eq!(coordinates(90., 180.).to_plus_code(20), "C2X2X2X2+X2RRRRR");
+    }
+
+    #[test]
+    fn decoding() {
+        let decoded = Coordinates::from_plus_code("8fvc9g8f+6x").unwrap();
+        assert!((f64::from(decoded.latitude) - 47.36559).abs() < 0.000125);
+        assert!((f64::from(decoded.longitude) - 8.524997).abs() < 0.000125);
+
+        assert!(Coordinates::from_plus_code("9F4M0000+").is_ok());
+
+        let invalid = |code: &str| matches!(Coordinates::from_plus_code(code), Err(Error::Invalid(_)));
+        assert!(invalid("9F4MGCC422"));
+        assert!(invalid("9F4MGCC+422"));
+        assert!(invalid("9F4MGCC4+2"));
+        assert!(invalid("9F4MGCA4+22"));
+        assert!(invalid("9F4M0000+22"));
+        assert!(invalid("9F400M00+"));
+        assert!(invalid("9F40000+"));
+        assert!(invalid("X24MGCC4+22"));
+        assert!(matches!(Coordinates::from_plus_code("GCC4+22"), Err(Error::ShortCode(_))));
+    }
+
+    #[test]
+    fn round_trips_at_range_limits() {
+        let limits = [(-90., -180.), (-90., 180.), (90., -180.), (90., 180.), (0., 0.), (52.52, 13.405)];
+        let lengths = (MIN_LENGTH..10).step_by(2).chain(10..=12);
+
+        for length in lengths {
+            let (height, width) = cell_size(length);
+
+            for (latitude, longitude) in limits {
+                let code = coordinates(latitude, longitude).to_plus_code(length);
+                let decoded = Coordinates::from_plus_code(&code).unwrap();
+
+                // 180° is encoded as -180°
+                let longitude = if longitude == 180. { -180. } else { longitude };
+
+                assert!((f64::from(decoded.latitude) - latitude).abs() <= height / 2. + 1e-6, "{code}: {decoded}");
+                assert!((f64::from(decoded.longitude) - longitude).abs() <= width / 2. + 1e-6, "{code}: {decoded}");
+                assert_eq!(decoded.to_plus_code(length), code);
+            }
+        }
+
+        assert!(Coordinates::from_plus_code(&coordinates(90., 180.).to_plus_code(MAX_LENGTH)).is_ok());
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt::{self, Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::data::Measurement;
+use crate::i18n::Locale;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum PollenSpecies {
+    Alder,
+    Birch,
+    Grass,
+    Mugwort,
+    Olive,
+    Ragweed,
+}
+
+impl PollenSpecies {
+    /// Name of the pollen rather than of the plant, e.g. "Birch pollen".
+    pub fn pollen_name(&self, locale: Locale) -> &'static str {
+        let (english, german, french) = match self {
+            PollenSpecies::Alder => ("Alder pollen", "Erlenpollen", "Pollen d'aulne"),
+            PollenSpecies::Birch => ("Birch pollen", "Birkenpollen", "Pollen de bouleau"),
+            PollenSpecies::Grass => ("Grass pollen", "Gräserpollen", "Pollen de graminées"),
+            PollenSpecies::Mugwort => ("Mugwort pollen", "Beifußpollen", "Pollen d'armoise"),
+            PollenSpecies::Olive => ("Olive pollen", "Olivenpollen", "Pollen d'olivier"),
+            PollenSpecies::Ragweed => ("Ragweed pollen", "Ambrosiapollen", "Pollen d'ambroisie"),
+        };
+
+        locale.select(english, german, french)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum PollenCount {
+    GrainsPerCubicMeter(f32),
+}
+
+impl Display for PollenCount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&f32::from(*self), f)?;
+        write!(f, " {}", self.unit())
+    }
+}
+
+impl From<PollenCount> for f32 {
+    fn from(value: PollenCount) -> Self {
+        match value {
+            PollenCount::GrainsPerCubicMeter(value) => value,
+        }
+    }
+}
+
+impl Measurement for PollenCount {
+    fn unit(&self) -> &'static str {
+        match self {
+            PollenCount::GrainsPerCubicMeter(_) => "grains/m³",
+        }
+    }
+
+    fn decimal_places(&self) -> usize {
+        0
+    }
+}
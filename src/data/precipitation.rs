@@ -0,0 +1,126 @@
+use std::fmt::{self, Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::data::{Measurement, UnitSystem};
+
+const MILLIMETERS_PER_INCH: f64 = 25.4;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Precipitation {
+    Millimeters(f32),
+    Inches(f32),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PrecipitationUnit {
+    Millimeters,
+    Inches,
+}
+
+impl PrecipitationUnit {
+    pub const ALL: [PrecipitationUnit; 2] = [PrecipitationUnit::Millimeters, PrecipitationUnit::Inches];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PrecipitationUnit::Millimeters => "mm",
+            PrecipitationUnit::Inches => "in",
+        }
+    }
+}
+
+impl Precipitation {
+    pub fn precipitation_unit(&self) -> PrecipitationUnit {
+        match self {
+            Precipitation::Millimeters(_) => PrecipitationUnit::Millimeters,
+            Precipitation::Inches(_) => PrecipitationUnit::Inches,
+        }
+    }
+
+    pub fn to_millimeters(self) -> Precipitation {
+        match self {
+            Precipitation::Millimeters(_) => self,
+            Precipitation::Inches(value) => Precipitation::Millimeters((value as f64 * MILLIMETERS_PER_INCH) as f32),
+        }
+    }
+
+    pub fn to_inches(self) -> Precipitation {
+        match self {
+            Precipitation::Inches(_) => self,
+            Precipitation::Millimeters(value) => Precipitation::Inches((value as f64 / MILLIMETERS_PER_INCH) as f32),
+        }
+    }
+
+    pub fn to_unit(self, unit: PrecipitationUnit) -> Precipitation {
+        match unit {
+            PrecipitationUnit::Millimeters => self.to_millimeters(),
+            PrecipitationUnit::Inches => self.to_inches(),
+        }
+    }
+}
+
+impl Display for Precipitation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&f32::from(*self), f)?;
+        write!(f, " {}", self.unit())
+    }
+}
+
+impl From<Precipitation> for f32 {
+    fn from(value: Precipitation) -> Self {
+        match value {
+            Precipitation::Millimeters(value) => value,
+            Precipitation::Inches(value) => value,
+        }
+    }
+}
+
+impl Measurement for Precipitation {
+    fn unit(&self) -> &'static str {
+        self.precipitation_unit().symbol()
+    }
+
+    fn decimal_places(&self) -> usize {
+        match self {
+            Precipitation::Millimeters(_) => 1,
+            Precipitation::Inches(_) => 2,
+        }
+    }
+
+    fn to_unit_system(self, unit_system: UnitSystem) -> Self {
+        self.to_unit(unit_system.precipitation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{Measurement, Precipitation, PrecipitationUnit, UnitSystem};
+
+    fn assert_close(actual: Precipitation, expected: Precipitation) {
+        assert_eq!(actual.precipitation_unit(), expected.precipitation_unit());
+        assert!((f32::from(actual) - f32::from(expected)).abs() < 1e-3, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn reference_points() {
+        assert_close(Precipitation::Inches(1.).to_millimeters(), Precipitation::Millimeters(25.4));
+        assert_close(Precipitation::Millimeters(10.).to_inches(), Precipitation::Inches(0.3937));
+        assert_close(Precipitation::Millimeters(0.).to_inches(), Precipitation::Inches(0.));
+    }
+
+    #[test]
+    fn round_trips() {
+        for tenths in 0..=2000 {
+            let precipitation = Precipitation::Millimeters(tenths as f32 / 10.);
+
+            for unit in PrecipitationUnit::ALL {
+                assert_close(precipitation.to_unit(unit).to_millimeters(), precipitation);
+            }
+        }
+    }
+
+    #[test]
+    fn unit_systems() {
+        assert_close(Precipitation::Millimeters(4.2).to_unit_system(UnitSystem::IMPERIAL), Precipitation::Inches(0.16535));
+        assert_close(Precipitation::Inches(0.5).to_unit_system(UnitSystem::METRIC), Precipitation::Millimeters(12.7));
+        assert_close(Precipitation::Millimeters(4.2).to_unit_system(UnitSystem::METRIC), Precipitation::Millimeters(4.2));
+    }
+}
@@ -1,14 +1,101 @@
 use std::fmt::{self, Display, Formatter};
-use crate::data::Measurement;
+use serde::{Deserialize, Serialize};
+use crate::data::{Measurement, UnitSystem};
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+const HECTO_PASCAL_PER_KILO_PASCAL: f64 = 10.;
+const HECTO_PASCAL_PER_INCH_OF_MERCURY: f64 = 33.863_886_666_7;
+const HECTO_PASCAL_PER_MILLIMETER_OF_MERCURY: f64 = 1.333_223_874_15;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Pressure {
     HectoPascal(f32),
+    KiloPascal(f32),
+    InchesOfMercury(f32),
+    MillimetersOfMercury(f32),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PressureUnit {
+    HectoPascal,
+    KiloPascal,
+    InchesOfMercury,
+    MillimetersOfMercury,
+}
+
+impl PressureUnit {
+    pub const ALL: [PressureUnit; 4] = [PressureUnit::HectoPascal, PressureUnit::KiloPascal, PressureUnit::InchesOfMercury, PressureUnit::MillimetersOfMercury];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            PressureUnit::HectoPascal => "hPa",
+            PressureUnit::KiloPascal => "kPa",
+            PressureUnit::InchesOfMercury => "inHg",
+            PressureUnit::MillimetersOfMercury => "mmHg",
+        }
+    }
+}
+
+impl Pressure {
+    pub fn pressure_unit(&self) -> PressureUnit {
+        match self {
+            Pressure::HectoPascal(_) => PressureUnit::HectoPascal,
+            Pressure::KiloPascal(_) => PressureUnit::KiloPascal,
+            Pressure::InchesOfMercury(_) => PressureUnit::InchesOfMercury,
+            Pressure::MillimetersOfMercury(_) => PressureUnit::MillimetersOfMercury,
+        }
+    }
+
+    fn hecto_pascal(&self) -> f64 {
+        match *self {
+            Pressure::HectoPascal(value) => value as f64,
+            Pressure::KiloPascal(value) => value as f64 * HECTO_PASCAL_PER_KILO_PASCAL,
+            Pressure::InchesOfMercury(value) => value as f64 * HECTO_PASCAL_PER_INCH_OF_MERCURY,
+            Pressure::MillimetersOfMercury(value) => value as f64 * HECTO_PASCAL_PER_MILLIMETER_OF_MERCURY,
+        }
+    }
+
+    pub fn to_hecto_pascal(self) -> Pressure {
+        match self {
+            Pressure::HectoPascal(_) => self,
+            _ => Pressure::HectoPascal(self.hecto_pascal() as f32),
+        }
+    }
+
+    pub fn to_kilo_pascal(self) -> Pressure {
+        match self {
+            Pressure::KiloPascal(_) => self,
+            _ => Pressure::KiloPascal((self.hecto_pascal() / HECTO_PASCAL_PER_KILO_PASCAL) as f32),
+        }
+    }
+
+    pub fn to_inches_of_mercury(self) -> Pressure {
+        match self {
+            Pressure::InchesOfMercury(_) => self,
+            _ => Pressure::InchesOfMercury((self.hecto_pascal() / HECTO_PASCAL_PER_INCH_OF_MERCURY) as f32),
+        }
+    }
+
+    pub fn to_millimeters_of_mercury(self) -> Pressure {
+        match self {
+            Pressure::MillimetersOfMercury(_) => self,
+            _ => Pressure::MillimetersOfMercury((self.hecto_pascal() / HECTO_PASCAL_PER_MILLIMETER_OF_MERCURY) as f32),
+        }
+    }
+
+    pub fn to_unit(self, unit: PressureUnit) -> Pressure {
+        match unit {
+            PressureUnit::HectoPascal => self.to_hecto_pascal(),
+            PressureUnit::KiloPascal => self.to_kilo_pascal(),
+            PressureUnit::InchesOfMercury => self.to_inches_of_mercury(),
+            PressureUnit::MillimetersOfMercury => self.to_millimeters_of_mercury(),
+        }
+    }
 }
 
 impl Display for Pressure {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", f32::from(*self), self.unit())
+        Display::fmt(&f32::from(*self), f)?;
+        write!(f, " {}", self.unit())
     }
 }
 
@@ -16,14 +103,56 @@ impl From<Pressure> for f32 {
     fn from(value: Pressure) -> Self {
         match value {
             Pressure::HectoPascal(value) => value,
+            Pressure::KiloPascal(value) => value,
+            Pressure::InchesOfMercury(value) => value,
+            Pressure::MillimetersOfMercury(value) => value,
         }
     }
 }
 
 impl Measurement for Pressure {
     fn unit(&self) -> &'static str {
+        self.pressure_unit().symbol()
+    }
+
+    fn decimal_places(&self) -> usize {
         match self {
-            Pressure::HectoPascal(_) => "hPa",
+            Pressure::HectoPascal(_) | Pressure::MillimetersOfMercury(_) => 0,
+            Pressure::KiloPascal(_) => 1,
+            Pressure::InchesOfMercury(_) => 2,
+        }
+    }
+
+    fn to_unit_system(self, unit_system: UnitSystem) -> Self {
+        self.to_unit(unit_system.pressure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{Pressure, PressureUnit};
+
+    fn assert_close(actual: Pressure, expected: Pressure) {
+        assert_eq!(actual.pressure_unit(), expected.pressure_unit());
+        assert!((f32::from(actual) - f32::from(expected)).abs() < 1e-3, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn reference_points() {
+        assert_close(Pressure::HectoPascal(1013.25).to_kilo_pascal(), Pressure::KiloPascal(101.325));
+        assert_close(Pressure::HectoPascal(1013.25).to_inches_of_mercury(), Pressure::InchesOfMercury(29.921));
+        assert_close(Pressure::HectoPascal(1013.25).to_millimeters_of_mercury(), Pressure::MillimetersOfMercury(760.));
+        assert_close(Pressure::InchesOfMercury(29.921).to_millimeters_of_mercury(), Pressure::MillimetersOfMercury(759.993));
+    }
+
+    #[test]
+    fn round_trips() {
+        for hecto_pascal in 850..=1090 {
+            let pressure = Pressure::HectoPascal(hecto_pascal as f32);
+
+            for unit in PressureUnit::ALL {
+                assert_close(pressure.to_unit(unit).to_hecto_pascal(), pressure);
+            }
         }
     }
 }
@@ -1,14 +1,101 @@
 use std::fmt::{self, Display, Formatter};
-use crate::data::Measurement;
+use serde::{Deserialize, Serialize};
+use crate::data::{Measurement, UnitSystem};
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+const METERS_PER_SECOND_PER_KILOMETER_PER_HOUR: f64 = 1. / 3.6;
+const METERS_PER_SECOND_PER_MILE_PER_HOUR: f64 = 0.44704;
+const METERS_PER_SECOND_PER_KNOT: f64 = 1852. / 3600.;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Speed {
     KilometersPerHour(f32),
+    MetersPerSecond(f32),
+    MilesPerHour(f32),
+    Knots(f32),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SpeedUnit {
+    KilometersPerHour,
+    MetersPerSecond,
+    MilesPerHour,
+    Knots,
+}
+
+impl SpeedUnit {
+    pub const ALL: [SpeedUnit; 4] = [SpeedUnit::KilometersPerHour, SpeedUnit::MetersPerSecond, SpeedUnit::MilesPerHour, SpeedUnit::Knots];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SpeedUnit::KilometersPerHour => "km/h",
+            SpeedUnit::MetersPerSecond => "m/s",
+            SpeedUnit::MilesPerHour => "mph",
+            SpeedUnit::Knots => "kn",
+        }
+    }
+}
+
+impl Speed {
+    pub fn speed_unit(&self) -> SpeedUnit {
+        match self {
+            Speed::KilometersPerHour(_) => SpeedUnit::KilometersPerHour,
+            Speed::MetersPerSecond(_) => SpeedUnit::MetersPerSecond,
+            Speed::MilesPerHour(_) => SpeedUnit::MilesPerHour,
+            Speed::Knots(_) => SpeedUnit::Knots,
+        }
+    }
+
+    fn meters_per_second(&self) -> f64 {
+        match *self {
+            Speed::KilometersPerHour(value) => value as f64 * METERS_PER_SECOND_PER_KILOMETER_PER_HOUR,
+            Speed::MetersPerSecond(value) => value as f64,
+            Speed::MilesPerHour(value) => value as f64 * METERS_PER_SECOND_PER_MILE_PER_HOUR,
+            Speed::Knots(value) => value as f64 * METERS_PER_SECOND_PER_KNOT,
+        }
+    }
+
+    pub fn to_kilometers_per_hour(self) -> Speed {
+        match self {
+            Speed::KilometersPerHour(_) => self,
+            _ => Speed::KilometersPerHour((self.meters_per_second() / METERS_PER_SECOND_PER_KILOMETER_PER_HOUR) as f32),
+        }
+    }
+
+    pub fn to_meters_per_second(self) -> Speed {
+        match self {
+            Speed::MetersPerSecond(_) => self,
+            _ => Speed::MetersPerSecond(self.meters_per_second() as f32),
+        }
+    }
+
+    pub fn to_miles_per_hour(self) -> Speed {
+        match self {
+            Speed::MilesPerHour(_) => self,
+            _ => Speed::MilesPerHour((self.meters_per_second() / METERS_PER_SECOND_PER_MILE_PER_HOUR) as f32),
+        }
+    }
+
+    pub fn to_knots(self) -> Speed {
+        match self {
+            Speed::Knots(_) => self,
+            _ => Speed::Knots((self.meters_per_second() / METERS_PER_SECOND_PER_KNOT) as f32),
+        }
+    }
+
+    pub fn to_unit(self, unit: SpeedUnit) -> Speed {
+        match unit {
+            SpeedUnit::KilometersPerHour => self.to_kilometers_per_hour(),
+            SpeedUnit::MetersPerSecond => self.to_meters_per_second(),
+            SpeedUnit::MilesPerHour => self.to_miles_per_hour(),
+            SpeedUnit::Knots => self.to_knots(),
+        }
+    }
 }
 
 impl Display for Speed {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", f32::from(*self), self.unit())
+        Display::fmt(&f32::from(*self), f)?;
+        write!(f, " {}", self.unit())
     }
 }
 
@@ -16,14 +103,48 @@ impl From<Speed> for f32 {
     fn from(value: Speed) -> Self {
         match value {
             Speed::KilometersPerHour(value) => value,
+            Speed::MetersPerSecond(value) => value,
+            Speed::MilesPerHour(value) => value,
+            Speed::Knots(value) => value,
         }
     }
 }
 
 impl Measurement for Speed {
     fn unit(&self) -> &'static str {
-        match self {
-            Speed::KilometersPerHour(_) => "km/h",
+        self.speed_unit().symbol()
+    }
+
+    fn to_unit_system(self, unit_system: UnitSystem) -> Self {
+        self.to_unit(unit_system.speed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{Speed, SpeedUnit};
+
+    fn assert_close(actual: Speed, expected: Speed) {
+        assert_eq!(actual.speed_unit(), expected.speed_unit());
+        assert!((f32::from(actual) - f32::from(expected)).abs() < 1e-3, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn reference_points() {
+        assert_close(Speed::KilometersPerHour(36.).to_meters_per_second(), Speed::MetersPerSecond(10.));
+        assert_close(Speed::MilesPerHour(1.).to_kilometers_per_hour(), Speed::KilometersPerHour(1.609344));
+        assert_close(Speed::Knots(1.).to_kilometers_per_hour(), Speed::KilometersPerHour(1.852));
+        assert_close(Speed::MetersPerSecond(0.44704).to_miles_per_hour(), Speed::MilesPerHour(1.));
+    }
+
+    #[test]
+    fn round_trips() {
+        for tenths in 0..=2000 {
+            let speed = Speed::KilometersPerHour(tenths as f32 / 10.);
+
+            for unit in SpeedUnit::ALL {
+                assert_close(speed.to_unit(unit).to_kilometers_per_hour(), speed);
+            }
         }
     }
 }
@@ -1,9 +1,80 @@
 use std::fmt::{self, Display, Formatter};
-use crate::data::Measurement;
+use serde::{Deserialize, Serialize};
+use crate::data::{Measurement, UnitSystem};
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+const KELVIN_AT_ZERO_CELSIUS: f64 = 273.15;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Temperature {
     Celsius(f32),
+    Fahrenheit(f32),
+    Kelvin(f32),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    pub const ALL: [TemperatureUnit; 3] = [TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit, TemperatureUnit::Kelvin];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
+}
+
+impl Temperature {
+    pub fn temperature_unit(&self) -> TemperatureUnit {
+        match self {
+            Temperature::Celsius(_) => TemperatureUnit::Celsius,
+            Temperature::Fahrenheit(_) => TemperatureUnit::Fahrenheit,
+            Temperature::Kelvin(_) => TemperatureUnit::Kelvin,
+        }
+    }
+
+    fn celsius(&self) -> f64 {
+        match *self {
+            Temperature::Celsius(value) => value as f64,
+            Temperature::Fahrenheit(value) => (value as f64 - 32.) * 5. / 9.,
+            Temperature::Kelvin(value) => value as f64 - KELVIN_AT_ZERO_CELSIUS,
+        }
+    }
+
+    pub fn to_celsius(self) -> Temperature {
+        match self {
+            Temperature::Celsius(_) => self,
+            _ => Temperature::Celsius(self.celsius() as f32),
+        }
+    }
+
+    pub fn to_fahrenheit(self) -> Temperature {
+        match self {
+            Temperature::Fahrenheit(_) => self,
+            _ => Temperature::Fahrenheit((self.celsius() * 9. / 5. + 32.) as f32),
+        }
+    }
+
+    pub fn to_kelvin(self) -> Temperature {
+        match self {
+            Temperature::Kelvin(_) => self,
+            _ => Temperature::Kelvin((self.celsius() + KELVIN_AT_ZERO_CELSIUS) as f32),
+        }
+    }
+
+    pub fn to_unit(self, unit: TemperatureUnit) -> Temperature {
+        match unit {
+            TemperatureUnit::Celsius => self.to_celsius(),
+            TemperatureUnit::Fahrenheit => self.to_fahrenheit(),
+            TemperatureUnit::Kelvin => self.to_kelvin(),
+        }
+    }
 }
 
 impl Display for Temperature {
@@ -16,7 +87,8 @@ impl Display for Temperature {
             format!(" {}", unit)
         };
 
-        write!(f, "{}{}", f32::from(*self), padded_unit)
+        Display::fmt(&f32::from(*self), f)?;
+        write!(f, "{}", padded_unit)
     }
 }
 
@@ -24,14 +96,64 @@ impl From<Temperature> for f32 {
     fn from(value: Temperature) -> Self {
         match value {
             Temperature::Celsius(value) => value,
+            Temperature::Fahrenheit(value) => value,
+            Temperature::Kelvin(value) => value,
         }
     }
 }
 
 impl Measurement for Temperature {
     fn unit(&self) -> &'static str {
-        match self {
-            Temperature::Celsius(_) => "°C",
+        self.temperature_unit().symbol()
+    }
+
+    fn to_unit_system(self, unit_system: UnitSystem) -> Self {
+        self.to_unit(unit_system.temperature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::Temperature;
+
+    fn assert_close(actual: Temperature, expected: Temperature) {
+        assert_eq!(actual.temperature_unit(), expected.temperature_unit());
+        assert!((f32::from(actual) - f32::from(expected)).abs() < 1e-4, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn reference_points() {
+        assert_close(Temperature::Celsius(0.).to_fahrenheit(), Temperature::Fahrenheit(32.));
+        assert_close(Temperature::Celsius(100.).to_fahrenheit(), Temperature::Fahrenheit(212.));
+        assert_close(Temperature::Celsius(-40.).to_fahrenheit(), Temperature::Fahrenheit(-40.));
+        assert_close(Temperature::Celsius(0.).to_kelvin(), Temperature::Kelvin(273.15));
+        assert_close(Temperature::Fahrenheit(32.).to_kelvin(), Temperature::Kelvin(273.15));
+        assert_close(Temperature::Kelvin(0.).to_celsius(), Temperature::Celsius(-273.15));
+    }
+
+    #[test]
+    fn conversion_to_same_unit_is_identity() {
+        let temperatures = [Temperature::Celsius(21.3), Temperature::Fahrenheit(70.34), Temperature::Kelvin(294.45)];
+
+        for temperature in temperatures {
+            assert_eq!(temperature.to_unit(temperature.temperature_unit()), temperature);
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        for tenths in -600..=600 {
+            let celsius = Temperature::Celsius(tenths as f32 / 10.);
+            assert_close(celsius.to_fahrenheit().to_celsius(), celsius);
+            assert_close(celsius.to_kelvin().to_celsius(), celsius);
+            assert_close(celsius.to_fahrenheit().to_kelvin().to_celsius(), celsius);
         }
     }
+
+    #[test]
+    fn display() {
+        assert_eq!(Temperature::Celsius(21.5).to_string(), "21.5°C");
+        assert_eq!(format!("{:.1}", Temperature::Fahrenheit(70.7)), "70.7°F");
+        assert_eq!(format!("{:.0}", Temperature::Kelvin(294.65)), "295 K");
+    }
 }
@@ -0,0 +1,41 @@
+use crate::data::{LengthUnit, PrecipitationUnit, PressureUnit, SpeedUnit, TemperatureUnit};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnitSystem {
+    pub temperature: TemperatureUnit,
+    pub speed: SpeedUnit,
+    pub pressure: PressureUnit,
+    pub precipitation: PrecipitationUnit,
+
+    /// Used for heights, e.g. of waves or places
+    pub length: LengthUnit,
+
+    /// Used for distances between places
+    pub distance: LengthUnit,
+}
+
+impl UnitSystem {
+    pub const METRIC: UnitSystem = UnitSystem {
+        temperature: TemperatureUnit::Celsius,
+        speed: SpeedUnit::KilometersPerHour,
+        pressure: PressureUnit::HectoPascal,
+        precipitation: PrecipitationUnit::Millimeters,
+        length: LengthUnit::Meters,
+        distance: LengthUnit::Kilometers,
+    };
+
+    pub const IMPERIAL: UnitSystem = UnitSystem {
+        temperature: TemperatureUnit::Fahrenheit,
+        speed: SpeedUnit::MilesPerHour,
+        pressure: PressureUnit::InchesOfMercury,
+        precipitation: PrecipitationUnit::Inches,
+        length: LengthUnit::Feet,
+        distance: LengthUnit::Miles,
+    };
+}
+
+impl Default for UnitSystem {
+    fn default() -> Self {
+        UnitSystem::METRIC
+    }
+}
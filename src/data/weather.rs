@@ -1,116 +1,89 @@
-use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use crate::i18n::Locale;
 use crate::util::CloneableStr;
 use crate::data::{wmo_code, WmoCode};
 
-#[derive(Clone, Debug)]
+const TABLE_SIZE: usize = wmo_code::MAX_VALUE as usize + 1;
+
+/// The description is looked up on demand, so that data cached in one locale can be shown in another.
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Weather {
-    pub description: CloneableStr,
+    pub wmo_code: WmoCode,
     pub icon_path: CloneableStr,
 }
 
-// Registry source files
-const WMO_JSON: &str = include_str!("../../compile_time_configs/wmo_codes.json");
-const DESCRIPTION_JSON: &str = include_str!("../../compile_time_configs/weather_descriptions.json");
-const ICON_FILE_NAMES_JSON: &str = include_str!("../../compile_time_configs/icon_file_names.json");
+impl Weather {
+    pub fn description(&self, locale: Locale) -> &'static str {
+        let descriptions = match locale {
+            Locale::English => &DESCRIPTIONS_EN,
+            Locale::German => &DESCRIPTIONS_DE,
+            Locale::French => &DESCRIPTIONS_FR,
+        };
 
-#[derive(Debug)]
-pub struct WeatherRegistry {
-    descriptions: HashMap<WmoCode, CloneableStr>,
-    icon_paths: HashMap<WmoCode, CloneableStr>,
+        descriptions[u8::from(self.wmo_code) as usize]
+    }
 }
 
-impl WeatherRegistry {
-    pub fn load() -> Result<WeatherRegistry, Error> {
-        let wmo_code_values = serde_json::from_str::<HashMap<u8, String>>(WMO_JSON)?;
-        let descriptions_from_keys = serde_json::from_str::<HashMap<String, String>>(DESCRIPTION_JSON)?;
-        let icon_file_names_from_keys = serde_json::from_str::<HashMap<String, String>>(ICON_FILE_NAMES_JSON)?;
-
-        let wmo_codes = wmo_code_values.into_iter().map(|(wmo_code_value, weather)| {
-            WmoCode::try_from(wmo_code_value).map(|wmo_code| (wmo_code, weather))
-        }).collect::<Result<HashMap<_, _>, _>>()?;
-
-        for wmo_code in (0..=wmo_code::MAX_VALUE).flat_map(WmoCode::try_from) {
-            if !wmo_codes.contains_key(&wmo_code) {
-                return Err(Error::WmoCodeDefinitionMissing(wmo_code));
-            }
-        }
-
-        let valid_keys = wmo_codes.values().collect::<HashSet<_>>();
-
-        for key in descriptions_from_keys.keys() {
-            if !valid_keys.contains(key) {
-                return Err(Error::DescriptionForUndefinedKey(key.to_owned()));
-            }
-        }
-
-        for key in icon_file_names_from_keys.keys() {
-            if !valid_keys.contains(key) {
-                return Err(Error::IconPathForUndefinedKey(key.to_owned()));
-            }
-        }
-
-        let mut descriptions = HashMap::new();
-        let mut icon_paths = HashMap::new();
-
-        for (wmo_code, key) in wmo_codes {
-            let description = descriptions_from_keys
-                .get(&key)
-                .ok_or(Error::DescriptionMissing(key.clone()))?
-                .clone();
-
-            descriptions.insert(wmo_code, description.into());
+struct WeatherIcons {
+    day_icon_path: &'static str,
+    night_icon_path: Option<&'static str>,
+}
 
-            let icon_file_name = icon_file_names_from_keys
-                .get(&key)
-                .ok_or(Error::IconPathMissing(key.clone()))?;
+// Generated by `build.rs` from the files in `compile_time_configs`, indexed by the value of the WMO code.
+static ICONS: [WeatherIcons; TABLE_SIZE] = include!(concat!(env!("OUT_DIR"), "/weather_icons.rs"));
+static DESCRIPTIONS_EN: [&str; TABLE_SIZE] = include!(concat!(env!("OUT_DIR"), "/weather_descriptions_en.rs"));
+static DESCRIPTIONS_DE: [&str; TABLE_SIZE] = include!(concat!(env!("OUT_DIR"), "/weather_descriptions_de.rs"));
+static DESCRIPTIONS_FR: [&str; TABLE_SIZE] = include!(concat!(env!("OUT_DIR"), "/weather_descriptions_fr.rs"));
 
-            let icon_path = format!("assets/icons/{}.svg", icon_file_name);
-            icon_paths.insert(wmo_code, icon_path.into());
-        }
+/// Descriptions and icons of all WMO codes. The configuration is validated at build time, so lookups cannot fail.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WeatherRegistry;
 
-        let weather_registry = WeatherRegistry { descriptions, icon_paths };
-
-        Ok(weather_registry)
-    }
+impl WeatherRegistry {
+    /// Falls back to the day icon at night if there is no dedicated night icon.
+    pub fn get(wmo_code: WmoCode, is_day: bool) -> Weather {
+        let icons = &ICONS[u8::from(wmo_code) as usize];
+        let night_icon_path = (!is_day).then_some(icons.night_icon_path).flatten();
 
-    pub fn get(&self, wmo_code: WmoCode) -> Weather {
         Weather {
-            description: self.descriptions.get(&wmo_code).unwrap().clone(),
-            icon_path: self.icon_paths.get(&wmo_code).unwrap().clone(),
+            wmo_code,
+            icon_path: night_icon_path.unwrap_or(icons.day_icon_path).into(),
         }
     }
 }
 
-#[derive(Debug, thiserror::Error)]
-pub enum Error {
-    #[error("Failed to deserialize the source file: {0}")]
-    FailedToDeserializeSources(#[from] serde_json::Error),
-
-    #[error("Invalid value for WMO code: {0}")]
-    InvalidValueForWmoCode(#[from] wmo_code::Error),
-
-    #[error("WMO code definition missing for code {}", u8::from(*.0))]
-    WmoCodeDefinitionMissing(WmoCode),
-
-    #[error("Description defined for non-existent key {0}")]
-    DescriptionForUndefinedKey(String),
+#[cfg(test)]
+mod weather_tests {
+    use crate::data::{wmo_code, WeatherRegistry, WmoCode};
+    use crate::i18n::Locale;
 
-    #[error("Description missing for key {0}")]
-    DescriptionMissing(String),
+    #[test]
+    fn every_wmo_code_has_an_entry() {
+        for wmo_code in (0..=wmo_code::MAX_VALUE).flat_map(WmoCode::try_from) {
+            let weather = WeatherRegistry::get(wmo_code, true);
+            assert!(weather.icon_path.starts_with("assets/icons/"));
 
-    #[error("Icon path defined for non-existent key {0}")]
-    IconPathForUndefinedKey(String),
+            for locale in Locale::ALL {
+                assert!(!weather.description(locale).is_empty());
+            }
+        }
+    }
 
-    #[error("Icon path missing for key {0}")]
-    IconPathMissing(String),
-}
+    #[test]
+    fn night_icons() {
+        let icon_path = |wmo_code: u8, is_day: bool| WeatherRegistry::get(WmoCode::try_from(wmo_code).unwrap(), is_day).icon_path;
 
-#[cfg(test)]
-mod weather_tests {
-    use crate::data::WeatherRegistry;
+        assert_eq!(&*icon_path(0, true), "assets/icons/sun.svg");
+        assert_eq!(&*icon_path(0, false), "assets/icons/moon.svg");
+        assert_eq!(&*icon_path(3, false), &*icon_path(3, true));
+    }
 
     #[test]
-    fn weather_registry_loads_without_errors() {
-        assert!(WeatherRegistry::load().is_ok());
+    fn localised_descriptions() {
+        let weather = WeatherRegistry::get(WmoCode::try_from(45).unwrap(), true);
+
+        assert_eq!(weather.description(Locale::English), "Fog");
+        assert_eq!(weather.description(Locale::German), "Nebel");
+        assert_eq!(weather.description(Locale::French), "Brouillard");
     }
 }
@@ -1,8 +1,11 @@
+use serde::{Deserialize, Serialize};
+
 pub const MAX_VALUE: u8 = 99;
 
 /// Code following the WMO interpretation standard as documented in
 /// https://www.nodc.noaa.gov/archive/arc0021/0002199/1.1/data/0-data/HTML/WMO-CODE/WMO4677.HTM
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "u8", into = "u8")]
 pub struct WmoCode(u8);
 
 impl TryFrom<u8> for WmoCode {
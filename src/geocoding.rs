@@ -0,0 +1,142 @@
+use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+use crate::data::{coordinates, Coordinates, Latitude, Length, Longitude};
+use crate::http::{self, RequestPolicy};
+use crate::util::{CloneableStr, LocalBoxFuture};
+
+pub const OPEN_METEO_GEOCODING_URL: &str = "https://geocoding-api.open-meteo.com/v1/search";
+const DEFAULT_RESULT_COUNT: u8 = 10;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Place {
+    pub name: String,
+    pub admin_region: Option<String>,
+    pub country: Option<String>,
+    pub coordinates: Coordinates,
+    pub elevation: Option<Length>,
+    pub timezone: Option<String>,
+}
+
+impl Display for Place {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+
+        for part in [&self.admin_region, &self.country].into_iter().flatten() {
+            write!(f, ", {}", part)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub trait Geocoder {
+    fn search<'a>(&'a self, query: &'a str) -> LocalBoxFuture<'a, Result<Vec<Place>, Error>>;
+}
+
+/// Geocoder for the Open-Meteo geocoding API or any server exposing the same interface,
+/// e.g. a local mock server.
+#[derive(Clone, Debug)]
+pub struct OpenMeteoGeocoder {
+    base_url: CloneableStr,
+    result_count: u8,
+    request_policy: RequestPolicy,
+}
+
+impl OpenMeteoGeocoder {
+    pub fn with_base_url(base_url: impl Into<CloneableStr>) -> Self {
+        OpenMeteoGeocoder {
+            base_url: base_url.into(),
+            result_count: DEFAULT_RESULT_COUNT,
+            request_policy: RequestPolicy::default(),
+        }
+    }
+
+    pub fn with_result_count(self, result_count: u8) -> Self {
+        OpenMeteoGeocoder { result_count, ..self }
+    }
+
+    pub fn with_request_policy(self, request_policy: RequestPolicy) -> Self {
+        OpenMeteoGeocoder { request_policy, ..self }
+    }
+}
+
+impl Default for OpenMeteoGeocoder {
+    fn default() -> Self {
+        OpenMeteoGeocoder::with_base_url(OPEN_METEO_GEOCODING_URL)
+    }
+}
+
+mod api_response {
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    pub struct Result {
+        pub name: String,
+        pub latitude: f32,
+        pub longitude: f32,
+        pub elevation: Option<f32>,
+        pub timezone: Option<String>,
+        pub country: Option<String>,
+        pub admin1: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Response {
+        #[serde(default)]
+        pub results: Vec<Result>,
+    }
+}
+
+impl Geocoder for OpenMeteoGeocoder {
+    fn search<'a>(&'a self, query: &'a str) -> LocalBoxFuture<'a, Result<Vec<Place>, Error>> {
+        Box::pin(async move {
+            let url = format!(
+                "{}?name={}&count={}&language=en&format=json",
+                self.base_url,
+                urlencoding::encode(query),
+                self.result_count,
+            );
+
+            let api_response = http::get(&url, &self.request_policy)
+                .await?
+                .parse::<api_response::Response>()?;
+
+            api_response.results.into_iter().map(|result| {
+                let coordinates = Coordinates {
+                    latitude: Latitude::try_from(result.latitude)?,
+                    longitude: Longitude::try_from(result.longitude)?,
+                };
+
+                Ok(Place {
+                    name: result.name,
+                    admin_region: result.admin1,
+                    country: result.country,
+                    coordinates,
+                    elevation: result.elevation.map(Length::Meters),
+                    timezone: result.timezone,
+                })
+            }).collect()
+        })
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The obtained coordinates are invalid: {0}")]
+    InvalidCoordinates(#[from] coordinates::Error),
+
+    #[error("The geocoding API could not be called successfully: {0}")]
+    ApiCall(Arc<reqwasm::Error>),
+
+    #[error("The geocoding API could not be reached: {0}")]
+    Request(#[from] http::Error),
+
+    #[error("The geocoding API responded with an error: {0}")]
+    Response(#[from] http::ResponseError),
+}
+
+impl From<reqwasm::Error> for Error {
+    fn from(error: reqwasm::Error) -> Self {
+        Error::ApiCall(Arc::new(error))
+    }
+}
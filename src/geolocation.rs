@@ -0,0 +1,151 @@
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use crate::data::{coordinates, Coordinates, Latitude, Longitude};
+use crate::util::LocalBoxFuture;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Error codes as defined in https://www.w3.org/TR/geolocation/#dom-geolocationpositionerror
+const PERMISSION_DENIED: u16 = 1;
+const POSITION_UNAVAILABLE: u16 = 2;
+const TIMEOUT: u16 = 3;
+
+pub trait GeolocationSource {
+    fn current_position(&self) -> LocalBoxFuture<'_, Result<Coordinates, Error>>;
+}
+
+pub fn coordinates_from_degrees(latitude: f64, longitude: f64) -> Result<Coordinates, Error> {
+    Ok(Coordinates {
+        latitude: Latitude::try_from(latitude)?,
+        longitude: Longitude::try_from(longitude)?,
+    })
+}
+
+/// Obtains the device position through the Geolocation API of the browser.
+#[derive(Clone, Debug)]
+pub struct BrowserGeolocation {
+    pub timeout: Duration,
+    pub high_accuracy: bool,
+}
+
+impl Default for BrowserGeolocation {
+    fn default() -> Self {
+        BrowserGeolocation { timeout: DEFAULT_TIMEOUT, high_accuracy: false }
+    }
+}
+
+impl GeolocationSource for BrowserGeolocation {
+    fn current_position(&self) -> LocalBoxFuture<'_, Result<Coordinates, Error>> {
+        Box::pin(async move {
+            let geolocation = web_sys::window()
+                .ok_or(Error::Unsupported)?
+                .navigator()
+                .geolocation()
+                .map_err(|_| Error::Unsupported)?;
+
+            let mut options = web_sys::PositionOptions::new();
+            options.timeout(self.timeout.as_millis().try_into().unwrap_or(u32::MAX));
+            options.enable_high_accuracy(self.high_accuracy);
+
+            let promise = js_sys::Promise::new(&mut |resolve, reject| {
+                let result = geolocation.get_current_position_with_error_callback_and_options(
+                    &resolve,
+                    Some(&reject),
+                    &options,
+                );
+
+                if let Err(error) = result {
+                    let _ = reject.call1(&wasm_bindgen::JsValue::NULL, &error);
+                }
+            });
+
+            match wasm_bindgen_futures::JsFuture::from(promise).await {
+                Ok(position) => {
+                    let coords = position.unchecked_into::<web_sys::Position>().coords();
+                    coordinates_from_degrees(coords.latitude(), coords.longitude())
+                },
+                Err(error) => Err(match error.dyn_into::<web_sys::PositionError>() {
+                    Ok(error) => Error::from_code(error.code(), error.message()),
+                    Err(_) => Error::Unsupported,
+                }),
+            }
+        })
+    }
+}
+
+/// Always reports the same position, e.g. for tests.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedGeolocation {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl GeolocationSource for FixedGeolocation {
+    fn current_position(&self) -> LocalBoxFuture<'_, Result<Coordinates, Error>> {
+        Box::pin(async move { coordinates_from_degrees(self.latitude, self.longitude) })
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Permission to access the device location was denied")]
+    PermissionDenied,
+
+    #[error("The device location is currently unavailable")]
+    PositionUnavailable,
+
+    #[error("The device location could not be determined in time")]
+    Timeout,
+
+    #[error("The browser does not support geolocation")]
+    Unsupported,
+
+    #[error("The device location could not be determined: {0}")]
+    Other(String),
+
+    #[error("The device location is not a valid coordinate: {0}")]
+    InvalidCoordinates(#[from] coordinates::Error),
+}
+
+impl Error {
+    fn from_code(code: u16, message: String) -> Self {
+        match code {
+            PERMISSION_DENIED => Error::PermissionDenied,
+            POSITION_UNAVAILABLE => Error::PositionUnavailable,
+            TIMEOUT => Error::Timeout,
+            _ => Error::Other(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use crate::geolocation::{Error, FixedGeolocation, GeolocationSource};
+
+    #[test]
+    fn fixed_position() {
+        let source = FixedGeolocation { latitude: 52.5, longitude: -13.25 };
+        let coordinates = block_on(source.current_position()).unwrap();
+
+        assert_eq!(f32::from(coordinates.latitude), 52.5);
+        assert_eq!(f32::from(coordinates.longitude), -13.25);
+    }
+
+    #[test]
+    fn invalid_position() {
+        let source = FixedGeolocation { latitude: 91., longitude: 13.41 };
+        assert!(matches!(block_on(source.current_position()), Err(Error::InvalidCoordinates(_))));
+
+        let source = FixedGeolocation { latitude: 52.52, longitude: f64::NAN };
+        assert!(matches!(block_on(source.current_position()), Err(Error::InvalidCoordinates(_))));
+    }
+
+    #[test]
+    fn error_codes() {
+        assert!(matches!(Error::from_code(1, String::new()), Error::PermissionDenied));
+        assert!(matches!(Error::from_code(2, String::new()), Error::PositionUnavailable));
+        assert!(matches!(Error::from_code(3, String::new()), Error::Timeout));
+        assert!(matches!(Error::from_code(4, "unknown".to_owned()), Error::Other(_)));
+    }
+}
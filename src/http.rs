@@ -0,0 +1,320 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+use reqwasm::http::Request;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RequestPolicy {
+    pub timeout: Duration,
+    pub max_retries: u8,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RequestPolicy {
+    fn default() -> Self {
+        RequestPolicy {
+            timeout: Duration::from_secs(10),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+        }
+    }
+}
+
+impl RequestPolicy {
+    /// Delay before the retry following the given (zero-based) failed attempt, doubling with each attempt.
+    pub fn backoff(&self, attempt: u8) -> Duration {
+        let factor = 2u32.saturating_pow(attempt as u32);
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+/// A response whose body has been read completely.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub retry_after: Option<String>,
+    pub body: String,
+}
+
+/// Body sent by the Open-Meteo APIs along with 4xx status codes, e.g. `{"error": true, "reason": "..."}` for out-of-range parameters.
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    reason: String,
+}
+
+impl Response {
+    /// Checks the status code before deserializing the body, so that error responses are not mistaken for invalid data.
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T, ResponseError> {
+        if !(200..300).contains(&self.status) {
+            let reason = serde_json::from_str::<ErrorBody>(&self.body).ok().map(|error_body| error_body.reason);
+
+            return Err(match (self.status, reason) {
+                (429, reason) => ResponseError::RateLimited { retry_after: self.retry_after.as_deref().and_then(parse_retry_after), reason },
+                (_, Some(reason)) => ResponseError::Rejected { reason },
+                (403, None) => ResponseError::Forbidden,
+                (status, None) => ResponseError::UnexpectedStatus { status },
+            });
+        }
+
+        serde_json::from_str(&self.body).map_err(|error| ResponseError::InvalidBody(Arc::new(error)))
+    }
+}
+
+pub fn is_online() -> bool {
+    web_sys::window()
+        .map(|window| window.navigator().on_line())
+        .unwrap_or(true)
+}
+
+/// Sends a GET request, retrying transient failures with exponential backoff.
+/// Responses with non-transient error status codes are returned as they are, so that callers can inspect them.
+pub async fn get(url: &str, policy: &RequestPolicy) -> Result<Response, Error> {
+    let mut attempt = 0;
+
+    loop {
+        let error = match send(url, policy.timeout).await {
+            Ok(response) if is_transient_status(response.status) => Error::ServerError { status: response.status },
+            Ok(response) => return Ok(response),
+            Err(error) => error,
+        };
+
+        if !error.is_transient() || attempt >= policy.max_retries {
+            return Err(error);
+        }
+
+        sleep(policy.backoff(attempt)).await;
+        attempt += 1;
+    }
+}
+
+async fn send(url: &str, timeout: Duration) -> Result<Response, Error> {
+    if !is_online() {
+        return Err(Error::Offline);
+    }
+
+    let controller = web_sys::AbortController::new().ok();
+    let signal = controller.as_ref().map(web_sys::AbortController::signal);
+    let timed_out = Rc::new(Cell::new(false));
+
+    let timeout_handle = {
+        let timed_out = timed_out.clone();
+
+        leptos::set_timeout_with_handle(move || {
+            timed_out.set(true);
+
+            if let Some(controller) = controller {
+                controller.abort();
+            }
+        }, timeout).ok()
+    };
+
+    // The timeout stays armed until the body has been read, as the server may stall after sending the headers.
+    let result = within_timeout(&timed_out, async {
+        let response = Request::get(url)
+            .abort_signal(signal.as_ref())
+            .send()
+            .await?;
+
+        Ok(Response {
+            status: response.status(),
+            retry_after: response.headers().get("Retry-After"),
+            body: response.text().await?,
+        })
+    }).await;
+
+    if let Some(timeout_handle) = timeout_handle {
+        timeout_handle.clear();
+    }
+
+    result
+}
+
+async fn within_timeout<T>(timed_out: &Cell<bool>, request: impl Future<Output = Result<T, reqwasm::Error>>) -> Result<T, Error> {
+    request.await.map_err(|error| {
+        if timed_out.get() {
+            Error::Timeout
+        } else if !is_online() {
+            Error::Offline
+        } else {
+            Error::Network(Arc::new(error))
+        }
+    })
+}
+
+async fn sleep(duration: Duration) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        let scheduled = web_sys::window().and_then(|window| {
+            window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                &resolve,
+                duration.as_millis().try_into().unwrap_or(i32::MAX),
+            ).ok()
+        });
+
+        if scheduled.is_none() {
+            let _ = resolve.call0(&wasm_bindgen::JsValue::NULL);
+        }
+    });
+
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+fn is_transient_status(status: u16) -> bool {
+    matches!(status, 500 | 502 | 503 | 504)
+}
+
+/// Only the delay in seconds is supported, the alternative HTTP date format is ignored.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+fn retry_after_hint(retry_after: &Option<Duration>) -> String {
+    match retry_after {
+        Some(retry_after) => format!(", retry after {} seconds", retry_after.as_secs()),
+        None => String::new(),
+    }
+}
+
+fn reason_hint(reason: &Option<String>) -> String {
+    match reason {
+        Some(reason) => format!(": {reason}"),
+        None => String::new(),
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The device is offline")]
+    Offline,
+
+    #[error("The server did not respond in time")]
+    Timeout,
+
+    #[error("The server failed with status code {status}")]
+    ServerError { status: u16 },
+
+    #[error("The request failed: {0}")]
+    Network(Arc<reqwasm::Error>),
+}
+
+/// A response that arrived, but does not carry the requested data.
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum ResponseError {
+    #[error("The request was rejected: {reason}")]
+    Rejected { reason: String },
+
+    /// Sent e.g. by MET Norway when its terms of service are violated, like by a missing identification.
+    #[error("The server denied access")]
+    Forbidden,
+
+    #[error("The rate limit has been exceeded{}{}", retry_after_hint(.retry_after), reason_hint(.reason))]
+    RateLimited { retry_after: Option<Duration>, reason: Option<String> },
+
+    #[error("The server responded with status code {status}")]
+    UnexpectedStatus { status: u16 },
+
+    #[error("The response could not be decoded: {0}")]
+    InvalidBody(Arc<serde_json::Error>),
+}
+
+impl Error {
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::Offline => false,
+            Error::Timeout | Error::Network(_) => true,
+            Error::ServerError { status } => is_transient_status(*status),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::time::Duration;
+    use futures::executor::block_on;
+    use crate::http::{within_timeout, Error, RequestPolicy, Response, ResponseError};
+
+    fn response(status: u16, retry_after: Option<&str>, body: &str) -> Response {
+        Response { status, retry_after: retry_after.map(str::to_string), body: body.to_string() }
+    }
+
+    #[test]
+    fn exponential_backoff() {
+        let policy = RequestPolicy {
+            timeout: Duration::from_secs(10),
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+        };
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(4), Duration::from_secs(8));
+        assert_eq!(policy.backoff(5), Duration::from_secs(8));
+        assert_eq!(policy.backoff(u8::MAX), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn transient_errors() {
+        assert!(Error::Timeout.is_transient());
+        assert!(Error::ServerError { status: 503 }.is_transient());
+        assert!(!Error::ServerError { status: 501 }.is_transient());
+        assert!(!Error::Offline.is_transient());
+    }
+
+    #[test]
+    fn timed_out_body() {
+        let timed_out = Cell::new(false);
+
+        let result = block_on(within_timeout(&timed_out, async {
+            // The headers have arrived, but the body is aborted by the timeout while it is being read
+            timed_out.set(true);
+            Err::<String, _>(reqwasm::Error::from(serde_json::from_str::<()>("{").unwrap_err()))
+        }));
+
+        assert!(matches!(result, Err(Error::Timeout)));
+    }
+
+    #[test]
+    fn response_body() {
+        let response = response(200, None, "[1, 2]");
+
+        assert_eq!(response.parse::<Vec<u8>>().unwrap(), vec![1, 2]);
+        assert!(matches!(response.parse::<String>(), Err(ResponseError::InvalidBody(_))));
+    }
+
+    #[test]
+    fn error_responses() {
+        match response(400, None, r#"{"error": true, "reason": "Cannot initialize WeatherVariable from invalid String value"}"#).parse::<Vec<u8>>() {
+            Err(ResponseError::Rejected { reason }) => assert_eq!(reason, "Cannot initialize WeatherVariable from invalid String value"),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+
+        assert!(matches!(response(403, None, "<html>Forbidden</html>").parse::<Vec<u8>>(), Err(ResponseError::Forbidden)));
+        assert!(matches!(response(304, None, "").parse::<Vec<u8>>(), Err(ResponseError::UnexpectedStatus { status: 304 })));
+    }
+
+    #[test]
+    fn rate_limits() {
+        match response(429, Some(" 30 "), "").parse::<Vec<u8>>() {
+            Err(error @ ResponseError::RateLimited { retry_after: Some(retry_after), reason: None }) => {
+                assert_eq!(retry_after, Duration::from_secs(30));
+                assert_eq!(error.to_string(), "The rate limit has been exceeded, retry after 30 seconds");
+            },
+            result => panic!("Unexpected result: {:?}", result),
+        }
+
+        match response(429, None, r#"{"error": true, "reason": "Minutely API request limit exceeded. Please try again in one minute."}"#).parse::<Vec<u8>>() {
+            Err(error @ ResponseError::RateLimited { retry_after: None, reason: Some(_) }) => {
+                assert_eq!(error.to_string(), "The rate limit has been exceeded: Minutely API request limit exceeded. Please try again in one minute.");
+            },
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,268 @@
+const STORAGE_KEY: &str = "stormy_skies.locale";
+
+/// Languages of the user interface. Weather descriptions are configured per locale in
+/// `compile_time_configs/weather_descriptions`, named by the `code`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Locale {
+    #[default]
+    English,
+    German,
+    French,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::English, Locale::German, Locale::French];
+
+    /// ISO 639-1 code of the language.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::German => "de",
+            Locale::French => "fr",
+        }
+    }
+
+    /// Name of the language in the language itself.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::German => "Deutsch",
+            Locale::French => "Français",
+        }
+    }
+
+    /// Only the primary language subtag is considered, e.g. `de-AT` is German.
+    pub fn from_language_tag(language_tag: &str) -> Option<Self> {
+        let primary_subtag = language_tag.split(['-', '_']).next()?;
+        Locale::ALL.into_iter().find(|locale| locale.code().eq_ignore_ascii_case(primary_subtag))
+    }
+
+    /// The first supported language of the preferred languages set in the browser.
+    pub fn from_browser() -> Option<Self> {
+        let navigator = web_sys::window()?.navigator();
+
+        navigator.languages().iter()
+            .filter_map(|language| language.as_string())
+            .chain(navigator.language())
+            .find_map(|language| Locale::from_language_tag(&language))
+    }
+
+    pub fn select<T>(self, english: T, german: T, french: T) -> T {
+        match self {
+            Locale::English => english,
+            Locale::German => german,
+            Locale::French => french,
+        }
+    }
+}
+
+/// Fixed texts of the user interface.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Text {
+    Coordinates,
+    SearchPlace,
+    UseMyLocation,
+    Days,
+    Forecast,
+    History,
+    Dashboard,
+    Name,
+    SaveLocation,
+    Metric,
+    Imperial,
+    Custom,
+    BrowserLanguage,
+    NoSavedLocations,
+    SavedLocationsHint,
+    Pending,
+    Unavailable,
+    MoveUp,
+    MoveDown,
+    Remove,
+    NoRequestMade,
+    Offline,
+    OfflineHint,
+    Error,
+    StaleData,
+    StaleDataRefreshFailed,
+    StaleDataWhileOffline,
+    FeelsLike,
+    DewPoint,
+    WindSpeed,
+    WindDirection,
+    Pressure,
+    Humidity,
+    AirQuality,
+    EuropeanAqi,
+    UnitedStatesAqi,
+    Ozone,
+    Marine,
+    Waves,
+    WindWaves,
+    Swell,
+    SeaSurfaceTemperature,
+    Daylight,
+    SunriseAndSunset,
+    PolarDay,
+    PolarNight,
+    DayLength,
+    SolarNoon,
+    CivilTwilight,
+    NauticalTwilight,
+    AstronomicalTwilight,
+    AllDay,
+    NoTwilight,
+    SunElevation,
+    SunAzimuth,
+    GridPoint,
+    SortByDistance,
+    WeatherDataBy,
+}
+
+impl Text {
+    pub fn translate(self, locale: Locale) -> &'static str {
+        let (english, german, french) = match self {
+            Text::Coordinates => ("Coordinates, e.g. 52.52, 13.40", "Koordinaten, z. B. 52.52, 13.40", "Coordonnées, p. ex. 52.52, 13.40"),
+            Text::SearchPlace => ("Search for a place", "Ort suchen", "Rechercher un lieu"),
+            Text::UseMyLocation => ("Use my location", "Meinen Standort verwenden", "Utiliser ma position"),
+            Text::Days => ("days", "Tage", "jours"),
+            Text::Forecast => ("Forecast", "Vorhersage", "Prévisions"),
+            Text::History => ("History", "Verlauf", "Historique"),
+            Text::Dashboard => ("Dashboard", "Übersicht", "Tableau de bord"),
+            Text::Name => ("Name", "Name", "Nom"),
+            Text::SaveLocation => ("Save location", "Ort speichern", "Enregistrer le lieu"),
+            Text::Metric => ("Metric", "Metrisch", "Métrique"),
+            Text::Imperial => ("Imperial", "Imperial", "Impérial"),
+            Text::Custom => ("Custom", "Benutzerdefiniert", "Personnalisé"),
+            Text::BrowserLanguage => ("Browser language", "Browsersprache", "Langue du navigateur"),
+            Text::NoSavedLocations => ("No saved locations", "Keine gespeicherten Orte", "Aucun lieu enregistré"),
+            Text::SavedLocationsHint => (
+                "Locations can be saved in the forecast view.",
+                "Orte können in der Vorhersage gespeichert werden.",
+                "Les lieux peuvent être enregistrés dans les prévisions.",
+            ),
+            Text::Pending => ("Pending...", "Wird geladen...", "Chargement..."),
+            Text::Unavailable => ("Unavailable", "Nicht verfügbar", "Indisponible"),
+            Text::MoveUp => ("Move up", "Nach oben", "Monter"),
+            Text::MoveDown => ("Move down", "Nach unten", "Descendre"),
+            Text::Remove => ("Remove", "Entfernen", "Supprimer"),
+            Text::NoRequestMade => ("No Request made", "Noch keine Anfrage", "Aucune requête effectuée"),
+            Text::Offline => ("You're offline", "Du bist offline", "Vous êtes hors ligne"),
+            Text::OfflineHint => (
+                "No weather data is available for this location yet. It will be loaded once you are back online.",
+                "Für diesen Ort liegen noch keine Wetterdaten vor. Sie werden geladen, sobald du wieder online bist.",
+                "Aucune donnée météo n'est encore disponible pour ce lieu. Elles seront chargées dès votre retour en ligne.",
+            ),
+            Text::Error => ("Error", "Fehler", "Erreur"),
+            Text::StaleData => (
+                "Showing cached data, refreshing...",
+                "Zwischengespeicherte Daten, wird aktualisiert...",
+                "Données en cache, actualisation...",
+            ),
+            Text::StaleDataRefreshFailed => (
+                "Showing cached data, refreshing failed. Retrying shortly...",
+                "Zwischengespeicherte Daten, die Aktualisierung ist fehlgeschlagen. Neuer Versuch in Kürze...",
+                "Données en cache, l'actualisation a échoué. Nouvel essai sous peu...",
+            ),
+            Text::StaleDataWhileOffline => (
+                "You're offline — showing last known data",
+                "Du bist offline — letzte bekannte Daten",
+                "Vous êtes hors ligne — dernières données connues",
+            ),
+            Text::FeelsLike => ("Feels like", "Gefühlt", "Ressenti"),
+            Text::DewPoint => ("Dew point", "Taupunkt", "Point de rosée"),
+            Text::WindSpeed => ("Wind Speed", "Windgeschwindigkeit", "Vitesse du vent"),
+            Text::WindDirection => ("Wind Direction", "Windrichtung", "Direction du vent"),
+            Text::Pressure => ("Pressure", "Luftdruck", "Pression"),
+            Text::Humidity => ("Humidity", "Luftfeuchtigkeit", "Humidité"),
+            Text::AirQuality => ("Air Quality", "Luftqualität", "Qualité de l'air"),
+            Text::EuropeanAqi => ("European AQI", "Europäischer LQI", "IQA européen"),
+            Text::UnitedStatesAqi => ("US AQI", "US-amerikanischer LQI", "IQA américain"),
+            Text::Ozone => ("Ozone", "Ozon", "Ozone"),
+            Text::Marine => ("Marine", "Meer", "Mer"),
+            Text::Waves => ("Waves", "Wellen", "Vagues"),
+            Text::WindWaves => ("Wind Waves", "Windsee", "Mer du vent"),
+            Text::Swell => ("Swell", "Dünung", "Houle"),
+            Text::SeaSurfaceTemperature => ("Sea Surface Temperature", "Wassertemperatur", "Température de la mer"),
+            Text::Daylight => ("Daylight", "Tageslicht", "Lumière du jour"),
+            Text::SunriseAndSunset => ("Sunrise – Sunset", "Sonnenaufgang – Sonnenuntergang", "Lever – Coucher du soleil"),
+            Text::PolarDay => ("Polar day", "Polartag", "Jour polaire"),
+            Text::PolarNight => ("Polar night", "Polarnacht", "Nuit polaire"),
+            Text::DayLength => ("Day Length", "Tageslänge", "Durée du jour"),
+            Text::SolarNoon => ("Solar Noon", "Sonnenhöchststand", "Midi solaire"),
+            Text::CivilTwilight => ("Civil Twilight", "Bürgerliche Dämmerung", "Crépuscule civil"),
+            Text::NauticalTwilight => ("Nautical Twilight", "Nautische Dämmerung", "Crépuscule nautique"),
+            Text::AstronomicalTwilight => ("Astronomical Twilight", "Astronomische Dämmerung", "Crépuscule astronomique"),
+            Text::AllDay => ("All day", "Ganztägig", "Toute la journée"),
+            Text::NoTwilight => ("None", "Keine", "Aucun"),
+            Text::SunElevation => ("Sun Elevation", "Sonnenhöhe", "Hauteur du soleil"),
+            Text::SunAzimuth => ("Sun Azimuth", "Sonnenazimut", "Azimut du soleil"),
+            Text::GridPoint => ("Grid Point", "Gitterpunkt", "Point de grille"),
+            Text::SortByDistance => ("Sort by distance", "Nach Entfernung sortieren", "Trier par distance"),
+            Text::WeatherDataBy => ("Weather data by", "Wetterdaten von", "Données météo par"),
+        };
+
+        locale.select(english, german, french)
+    }
+}
+
+/// Persists the locale chosen by the user in the `localStorage` of the browser, if it is accessible.
+/// No locale stored means that the language of the browser is used.
+#[derive(Clone, Debug, Default)]
+pub struct LocaleStore {
+    storage: Option<web_sys::Storage>,
+}
+
+impl LocaleStore {
+    pub fn with_local_storage() -> Self {
+        let storage = web_sys::window().and_then(|window| window.local_storage().ok().flatten());
+        LocaleStore { storage }
+    }
+
+    pub fn load(&self) -> Option<Locale> {
+        let storage = self.storage.as_ref()?;
+        let code = storage.get_item(STORAGE_KEY).ok()??;
+        Locale::from_language_tag(&code)
+    }
+
+    pub fn save(&self, locale: Option<Locale>) {
+        let Some(storage) = self.storage.as_ref() else {
+            return;
+        };
+
+        let _ = match locale {
+            Some(locale) => storage.set_item(STORAGE_KEY, locale.code()),
+            None => storage.remove_item(STORAGE_KEY),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::i18n::{Locale, Text};
+
+    #[test]
+    fn locales_from_language_tags() {
+        assert_eq!(Locale::from_language_tag("en"), Some(Locale::English));
+        assert_eq!(Locale::from_language_tag("en-GB"), Some(Locale::English));
+        assert_eq!(Locale::from_language_tag("de-AT"), Some(Locale::German));
+        assert_eq!(Locale::from_language_tag("DE"), Some(Locale::German));
+        assert_eq!(Locale::from_language_tag("fr_CA"), Some(Locale::French));
+        assert_eq!(Locale::from_language_tag("es-ES"), None);
+        assert_eq!(Locale::from_language_tag(""), None);
+    }
+
+    #[test]
+    fn locale_codes_round_trip() {
+        for locale in Locale::ALL {
+            assert_eq!(Locale::from_language_tag(locale.code()), Some(locale));
+        }
+    }
+
+    #[test]
+    fn translations() {
+        assert_eq!(Text::WindSpeed.translate(Locale::English), "Wind Speed");
+        assert_eq!(Text::WindSpeed.translate(Locale::German), "Windgeschwindigkeit");
+        assert_eq!(Text::WindSpeed.translate(Locale::French), "Vitesse du vent");
+    }
+}
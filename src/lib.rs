@@ -1,4 +1,18 @@
+pub mod air_quality;
+pub mod astronomy;
+pub mod cache;
 pub mod data;
+pub mod geocoding;
+pub mod geolocation;
+pub mod i18n;
+pub mod http;
+pub mod marine;
+pub mod met_norway;
 pub mod open_meteo;
+pub mod provider;
+pub mod routing;
+pub mod saved_locations;
+#[cfg(test)]
+mod test_util;
 pub mod ui;
 pub mod util;
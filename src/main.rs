@@ -1,9 +1,46 @@
 use std::rc::Rc;
+use std::time::Duration;
 use leptos::*;
-use stormy_skies::data::WeatherRegistry;
+use stormy_skies::air_quality::{AirQualitySource, OpenMeteoAirQuality};
+use stormy_skies::cache::WeatherCache;
+use stormy_skies::geocoding::{Geocoder, OpenMeteoGeocoder};
+use stormy_skies::geolocation::{BrowserGeolocation, GeolocationSource};
+use stormy_skies::i18n::LocaleStore;
+use stormy_skies::marine::{MarineSource, OpenMeteoMarine};
+use stormy_skies::met_norway::MetNorway;
+use stormy_skies::open_meteo::OpenMeteo;
+use stormy_skies::saved_locations::SavedLocationStore;
+use stormy_skies::provider::{WeatherArchive, WeatherProvider};
+
+const CACHE_TIME_TO_LIVE: Duration = Duration::from_secs(10 * 60);
 
 fn main() {
     console_error_panic_hook::set_once();
-    let weather_registry = Rc::new(WeatherRegistry::load().unwrap()); // TODO graceful error handling
-    mount_to_body(|| view! { <stormy_skies::ui::App weather_registry=weather_registry />} )
+    // The geocoding backend can be replaced at build time, e.g. by a local mock server
+    let geocoder: Rc<dyn Geocoder> = Rc::new(
+        option_env!("STORMY_SKIES_GEOCODING_URL")
+            .map(OpenMeteoGeocoder::with_base_url)
+            .unwrap_or_default()
+    );
+    let geolocation_source: Rc<dyn GeolocationSource> = Rc::new(BrowserGeolocation::default());
+    let weather_cache = Rc::new(WeatherCache::with_local_storage(CACHE_TIME_TO_LIVE));
+    let providers: Vec<Rc<dyn WeatherProvider>> = vec![Rc::new(OpenMeteo::default()), Rc::new(MetNorway::default())];
+    let weather_archive: Rc<dyn WeatherArchive> = Rc::new(OpenMeteo::default());
+    let air_quality_source: Rc<dyn AirQualitySource> = Rc::new(OpenMeteoAirQuality::default());
+    let marine_source: Rc<dyn MarineSource> = Rc::new(OpenMeteoMarine::default());
+    let saved_location_store = Rc::new(SavedLocationStore::with_local_storage());
+    let locale_store = Rc::new(LocaleStore::with_local_storage());
+    mount_to_body(|| view! {
+        <stormy_skies::ui::App
+            weather_cache=weather_cache
+            providers=providers
+            weather_archive=weather_archive
+            air_quality_source=air_quality_source
+            marine_source=marine_source
+            geocoder=geocoder
+            geolocation_source=geolocation_source
+            saved_location_store=saved_location_store
+            locale_store=locale_store
+        />
+    })
 }
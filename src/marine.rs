@@ -0,0 +1,195 @@
+use std::sync::Arc;
+use crate::data::{compass_direction, CompassDirection, Coordinates, Length, Period, Temperature};
+use crate::http::{self, RequestPolicy};
+use crate::util::LocalBoxFuture;
+
+const CURRENT_VARIABLES: &str = "wave_height,wave_direction,wave_period,wind_wave_height,wind_wave_direction,wind_wave_period,swell_wave_height,swell_wave_direction,swell_wave_period,sea_surface_temperature";
+
+/// Directions are given as the direction the waves are coming from, like wind directions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WaveComponent {
+    pub height: Length,
+    pub direction: CompassDirection,
+    pub period: Period,
+}
+
+/// Current sea state. The total waves are the combination of the wind waves and the swell.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarineConditions {
+    pub waves: WaveComponent,
+    pub wind_waves: Option<WaveComponent>,
+    pub swell: Option<WaveComponent>,
+    pub sea_surface_temperature: Option<Temperature>,
+}
+
+pub trait MarineSource {
+    /// Results in `None` for coordinates on land, where there are no waves.
+    fn current_marine_conditions(&self, coordinates: Coordinates) -> LocalBoxFuture<'_, Result<Option<MarineConditions>, Error>>;
+}
+
+/// Open-Meteo marine API, see https://open-meteo.com/en/docs/marine-weather-api.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OpenMeteoMarine {
+    pub request_policy: RequestPolicy,
+}
+
+mod api_response {
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    pub struct Current {
+        pub wave_height: Option<f32>,
+        pub wave_direction: Option<f32>,
+        pub wave_period: Option<f32>,
+        pub wind_wave_height: Option<f32>,
+        pub wind_wave_direction: Option<f32>,
+        pub wind_wave_period: Option<f32>,
+        pub swell_wave_height: Option<f32>,
+        pub swell_wave_direction: Option<f32>,
+        pub swell_wave_period: Option<f32>,
+        pub sea_surface_temperature: Option<f32>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Response {
+        pub current: Current,
+    }
+}
+
+impl MarineSource for OpenMeteoMarine {
+    fn current_marine_conditions(&self, coordinates: Coordinates) -> LocalBoxFuture<'_, Result<Option<MarineConditions>, Error>> {
+        Box::pin(async move {
+            let url = format!(
+                "https://marine-api.open-meteo.com/v1/marine?latitude={}&longitude={}&current={}&timezone=auto",
+                f64::from(coordinates.latitude),
+                f64::from(coordinates.longitude),
+                CURRENT_VARIABLES,
+            );
+
+            let api_response = http::get(&url, &self.request_policy)
+                .await?
+                .parse::<api_response::Response>()?;
+
+            convert(api_response.current)
+        })
+    }
+}
+
+/// The marine API answers requests for coordinates on land, but without any values.
+fn convert(current: api_response::Current) -> Result<Option<MarineConditions>, Error> {
+    let wave_component = |height: Option<f32>, direction: Option<f32>, period: Option<f32>| {
+        let (Some(height), Some(direction), Some(period)) = (height, direction, period) else {
+            return Ok(None);
+        };
+
+        Ok::<_, Error>(Some(WaveComponent {
+            height: Length::Meters(height),
+            direction: CompassDirection::from_degrees(direction)?,
+            period: Period::Seconds(period),
+        }))
+    };
+
+    let Some(waves) = wave_component(current.wave_height, current.wave_direction, current.wave_period)? else {
+        return Ok(None);
+    };
+
+    Ok(Some(MarineConditions {
+        waves,
+        wind_waves: wave_component(current.wind_wave_height, current.wind_wave_direction, current.wind_wave_period)?,
+        swell: wave_component(current.swell_wave_height, current.swell_wave_direction, current.swell_wave_period)?,
+        sea_surface_temperature: current.sea_surface_temperature.map(Temperature::Celsius),
+    }))
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The obtained direction is invalid: {0}")]
+    InvalidCompassDirection(#[from] compass_direction::Error),
+
+    #[error("The marine API could not be called successfully: {0}")]
+    ApiCall(Arc<reqwasm::Error>),
+
+    #[error("The marine API could not be reached: {0}")]
+    Request(#[from] http::Error),
+
+    #[error("The marine API responded with an error: {0}")]
+    Response(#[from] http::ResponseError),
+}
+
+impl From<reqwasm::Error> for Error {
+    fn from(error: reqwasm::Error) -> Self {
+        Error::ApiCall(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{CompassDirection, Length, Period, Temperature};
+    use crate::http::{Response, ResponseError};
+    use crate::marine::{api_response, convert, Error, MarineConditions};
+
+    fn convert_json(json: &str) -> Option<MarineConditions> {
+        let api_response = serde_json::from_str::<api_response::Response>(json).unwrap();
+        convert(api_response.current).unwrap()
+    }
+
+    #[test]
+    fn conditions_at_sea() {
+        let marine_conditions = convert_json(r#"{
+            "current": {
+                "time": "2024-06-01T12:00",
+                "wave_height": 1.42,
+                "wave_direction": 292.0,
+                "wave_period": 5.6,
+                "wind_wave_height": 0.9,
+                "wind_wave_direction": 280.0,
+                "wind_wave_period": 4.1,
+                "swell_wave_height": 0.84,
+                "swell_wave_direction": 310.0,
+                "swell_wave_period": 7.9,
+                "sea_surface_temperature": 14.3
+            }
+        }"#).unwrap();
+
+        assert_eq!(marine_conditions.waves.height, Length::Meters(1.42));
+        assert_eq!(marine_conditions.waves.direction, CompassDirection::W);
+        assert_eq!(marine_conditions.waves.period, Period::Seconds(5.6));
+        assert_eq!(marine_conditions.swell.unwrap().direction, CompassDirection::NW);
+        assert_eq!(marine_conditions.sea_surface_temperature, Some(Temperature::Celsius(14.3)));
+    }
+
+    #[test]
+    fn conditions_on_land() {
+        assert!(convert_json(r#"{
+            "current": {
+                "time": "2024-06-01T12:00",
+                "wave_height": null,
+                "wave_direction": null,
+                "wave_period": null,
+                "wind_wave_height": null,
+                "wind_wave_direction": null,
+                "wind_wave_period": null,
+                "swell_wave_height": null,
+                "swell_wave_direction": null,
+                "swell_wave_period": null,
+                "sea_surface_temperature": null
+            }
+        }"#).is_none());
+    }
+
+    #[test]
+    fn rejected_request() {
+        let response = Response {
+            status: 400,
+            retry_after: None,
+            body: r#"{"error": true, "reason": "Latitude must be in range of -90 to 90°. Given: 91.0."}"#.to_string(),
+        };
+
+        match response.parse::<api_response::Response>().map_err(Error::from) {
+            Err(error @ Error::Response(ResponseError::Rejected { .. })) => {
+                assert_eq!(error.to_string(), "The marine API responded with an error: The request was rejected: Latitude must be in range of -90 to 90°. Given: 91.0.");
+            },
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,451 @@
+use std::sync::Arc;
+use crate::astronomy;
+use crate::data::{bearing, date, Bearing, Coordinates, DateTime, Percentage, percentage, Precipitation, Pressure, Speed, Temperature, wmo_code, WmoCode};
+use crate::data::weather::WeatherRegistry;
+use crate::http::{self, RequestPolicy};
+use crate::provider::{self, DailyForecast, ForecastDays, HourlyForecast, WeatherData, WeatherProvider, HOURLY_FORECAST_HOURS};
+use crate::util::LocalBoxFuture;
+
+/// MET Norway Locationforecast, see https://api.met.no/weatherapi/locationforecast/2.0/documentation.
+/// All times reported by this provider are in UTC.
+/// The forecast reaches about nine and a half days ahead, so the last full day is the ninth.
+const FORECAST_DAYS: u8 = 9;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MetNorway {
+    pub request_policy: RequestPolicy,
+}
+
+impl WeatherProvider for MetNorway {
+    fn name(&self) -> &'static str {
+        "MET Norway"
+    }
+
+    fn attribution_url(&self) -> &'static str {
+        "https://api.met.no/"
+    }
+
+    fn max_forecast_days(&self) -> ForecastDays {
+        ForecastDays::try_from(FORECAST_DAYS).expect("MET Norway covers at least the minimum forecast length")
+    }
+
+    fn fetch<'a>(&'a self,
+                 coordinates: Coordinates,
+                 forecast_days: ForecastDays) -> LocalBoxFuture<'a, Result<WeatherData, provider::Error>> {
+        Box::pin(async move {
+            Ok(call_api(coordinates, forecast_days, &self.request_policy).await?)
+        })
+    }
+}
+
+mod api_response {
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    pub struct InstantDetails {
+        pub air_pressure_at_sea_level: f32,
+        pub air_temperature: f32,
+        pub relative_humidity: f32,
+        pub wind_from_direction: f32,
+        pub wind_speed: f32,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Instant {
+        pub details: InstantDetails,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Summary {
+        pub symbol_code: String,
+    }
+
+    #[derive(Debug, Default, Deserialize)]
+    pub struct PeriodDetails {
+        pub precipitation_amount: Option<f32>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Period {
+        pub summary: Summary,
+        #[serde(default)]
+        pub details: PeriodDetails,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Data {
+        pub instant: Instant,
+        pub next_1_hours: Option<Period>,
+        pub next_6_hours: Option<Period>,
+        pub next_12_hours: Option<Period>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct TimeStep {
+        pub time: String,
+        pub data: Data,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Properties {
+        pub timeseries: Vec<TimeStep>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Response {
+        pub properties: Properties,
+    }
+}
+
+pub async fn call_api(coordinates: Coordinates,
+                      forecast_days: ForecastDays,
+                      request_policy: &RequestPolicy) -> Result<WeatherData, Error> {
+
+    // MET Norway asks clients not to use more than four decimals
+    let url = format!(
+        "https://api.met.no/weatherapi/locationforecast/2.0/compact?lat={:.4}&lon={:.4}",
+        f64::from(coordinates.latitude),
+        f64::from(coordinates.longitude),
+    );
+
+    let api_response = http::get(&url, request_policy)
+        .await?
+        .parse::<api_response::Response>()?;
+
+    convert(coordinates, api_response, forecast_days)
+}
+
+fn convert(coordinates: Coordinates,
+           api_response: api_response::Response,
+           forecast_days: ForecastDays) -> Result<WeatherData, Error> {
+
+    let time_steps = api_response.properties.timeseries;
+    let current = time_steps.first().ok_or(Error::EmptyTimeSeries)?;
+    let current_details = &current.data.instant.details;
+
+    let current_symbol_code = summary_symbol_code(&current.data).ok_or(Error::EmptyTimeSeries)?;
+    let current_weather = WeatherRegistry::get(wmo_code_from_symbol_code(current_symbol_code)?, is_day(current_symbol_code));
+
+    let hourly_forecast = time_steps.iter()
+        .filter(|time_step| time_step.data.next_1_hours.is_some())
+        .take(HOURLY_FORECAST_HOURS as usize)
+        .map(convert_hourly)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let daily_forecast = convert_daily(coordinates, &time_steps, forecast_days)?;
+
+    Ok(WeatherData {
+        current_weather,
+        current_temperature: Temperature::Celsius(current_details.air_temperature),
+        current_wind_speed: Speed::MetersPerSecond(current_details.wind_speed),
+        current_wind_direction: Bearing::try_from(current_details.wind_from_direction)?,
+        current_pressure: Pressure::HectoPascal(current_details.air_pressure_at_sea_level),
+        current_humidity: humidity(current_details.relative_humidity)?,
+        hourly_forecast,
+        daily_forecast,
+        utc_offset_seconds: 0,
+        // The position returned is only the requested one, rounded to four decimals
+        grid_coordinates: None,
+    })
+}
+
+fn convert_hourly(time_step: &api_response::TimeStep) -> Result<HourlyForecast, Error> {
+
+    let details = &time_step.data.instant.details;
+    let next_hour = time_step.data.next_1_hours.as_ref().ok_or(Error::EmptyTimeSeries)?;
+
+    Ok(HourlyForecast {
+        time: parse_time(&time_step.time)?,
+        weather: WeatherRegistry::get(wmo_code_from_symbol_code(&next_hour.summary.symbol_code)?, is_day(&next_hour.summary.symbol_code)),
+        temperature: Temperature::Celsius(details.air_temperature),
+        wind_speed: Speed::MetersPerSecond(details.wind_speed),
+        wind_direction: Bearing::try_from(details.wind_from_direction)?,
+        pressure: Pressure::HectoPascal(details.air_pressure_at_sea_level),
+        humidity: humidity(details.relative_humidity)?,
+        precipitation: Precipitation::Millimeters(next_hour.details.precipitation_amount.unwrap_or(0.)),
+    })
+}
+
+/// Aggregates the time series into calendar days (UTC). The time steps are hourly for the first days
+/// and six-hourly afterwards, so the precipitation of each step is taken from the shortest period given.
+/// Sunrise and sunset are not part of the forecast and are calculated instead.
+fn convert_daily(coordinates: Coordinates,
+                 time_steps: &[api_response::TimeStep],
+                 forecast_days: ForecastDays) -> Result<Vec<DailyForecast>, Error> {
+
+    let mut days: Vec<(DateTime, Vec<&api_response::TimeStep>)> = Vec::new();
+
+    for time_step in time_steps {
+        let time = parse_time(&time_step.time)?;
+
+        match days.last_mut() {
+            Some((first_time, day)) if first_time.date() == time.date() => day.push(time_step),
+            _ => days.push((time, vec![time_step])),
+        }
+    }
+
+    days.into_iter().take(u8::from(forecast_days) as usize).map(|(first_time, day)| {
+        let temperatures = day.iter().map(|time_step| time_step.data.instant.details.air_temperature);
+        let wind_speeds = day.iter().map(|time_step| time_step.data.instant.details.wind_speed);
+
+        let precipitation_sum = day.iter()
+            .filter_map(|time_step| {
+                let data = &time_step.data;
+                data.next_1_hours.as_ref().or(data.next_6_hours.as_ref())
+            })
+            .filter_map(|period| period.details.precipitation_amount)
+            .sum();
+
+        // The weather around midday is most representative for the whole day
+        let representative = day.iter()
+            .min_by_key(|time_step| {
+                parse_time(&time_step.time).map(|time| time.hour().abs_diff(12)).unwrap_or(u8::MAX)
+            })
+            .ok_or(Error::EmptyTimeSeries)?;
+
+        let symbol_code = longest_period_symbol_code(&representative.data).ok_or(Error::EmptyTimeSeries)?;
+        let sunrise_and_sunset = astronomy::daylight(coordinates, first_time.date(), 0).sunrise_and_sunset;
+
+        Ok(DailyForecast {
+            date: first_time.date(),
+            weather: WeatherRegistry::get(wmo_code_from_symbol_code(symbol_code)?, true),
+            max_temperature: Temperature::Celsius(temperatures.clone().fold(f32::NEG_INFINITY, f32::max)),
+            min_temperature: Temperature::Celsius(temperatures.fold(f32::INFINITY, f32::min)),
+            precipitation_sum: Precipitation::Millimeters(precipitation_sum),
+            max_wind_speed: Speed::MetersPerSecond(wind_speeds.fold(0., f32::max)),
+            dominant_wind_direction: Bearing::try_from(dominant_wind_direction(&day))?,
+            sunrise: sunrise_and_sunset.rise(),
+            sunset: sunrise_and_sunset.set(),
+        })
+    }).collect()
+}
+
+/// Direction of the mean wind vector, so that strong winds weigh more than calm phases.
+fn dominant_wind_direction(day: &[&api_response::TimeStep]) -> f32 {
+    let (east, north) = day.iter()
+        .map(|time_step| &time_step.data.instant.details)
+        .fold((0., 0.), |(east, north), details| {
+            let radians = details.wind_from_direction.to_radians();
+            (east + details.wind_speed * radians.sin(), north + details.wind_speed * radians.cos())
+        });
+
+    east.atan2(north).to_degrees()
+}
+
+fn summary_symbol_code(data: &api_response::Data) -> Option<&str> {
+    [&data.next_1_hours, &data.next_6_hours, &data.next_12_hours]
+        .into_iter()
+        .flatten()
+        .map(|period| period.summary.symbol_code.as_str())
+        .next()
+}
+
+fn longest_period_symbol_code(data: &api_response::Data) -> Option<&str> {
+    [&data.next_12_hours, &data.next_6_hours, &data.next_1_hours]
+        .into_iter()
+        .flatten()
+        .map(|period| period.summary.symbol_code.as_str())
+        .next()
+}
+
+fn humidity(relative_humidity: f32) -> Result<Percentage, Error> {
+    Ok(Percentage::try_from(relative_humidity.round().clamp(0., u8::MAX as f32) as u8)?)
+}
+
+fn parse_time(time: &str) -> Result<DateTime, Error> {
+    // Times are given as e.g. "2024-02-10T14:00:00Z", seconds and time zone are dropped
+    let without_seconds = time.get(..16).ok_or(date::Error::InvalidFormat(time.to_owned()))?;
+    Ok(without_seconds.parse()?)
+}
+
+/// Only `_night` codes count as night, `_polartwilight` (the sun at low elevations) still counts as day.
+fn is_day(symbol_code: &str) -> bool {
+    !symbol_code.ends_with("_night")
+}
+
+/// Maps a symbol code as listed in https://api.met.no/weatherapi/weathericon/2.0/documentation
+/// onto the closest WMO code.
+pub fn wmo_code_from_symbol_code(symbol_code: &str) -> Result<WmoCode, Error> {
+    let base = symbol_code
+        .trim_end_matches("_day")
+        .trim_end_matches("_night")
+        .trim_end_matches("_polartwilight");
+
+    let value = match base {
+        "clearsky" => 0,
+        "fair" => 1,
+        "partlycloudy" => 2,
+        "cloudy" => 3,
+        "fog" => 45,
+        "lightrain" => 61,
+        "rain" => 63,
+        "heavyrain" => 65,
+        "lightsleet" | "sleet" => 68,
+        "heavysleet" => 69,
+        "lightsnow" => 71,
+        "snow" => 73,
+        "heavysnow" => 75,
+        "lightrainshowers" => 80,
+        "rainshowers" => 81,
+        "heavyrainshowers" => 82,
+        "lightsleetshowers" | "sleetshowers" => 83,
+        "heavysleetshowers" => 84,
+        "lightsnowshowers" | "snowshowers" => 85,
+        "heavysnowshowers" => 86,
+        thunder if thunder.ends_with("andthunder") && thunder.starts_with("heavy") => 97,
+        thunder if thunder.ends_with("andthunder") => 95,
+        _ => return Err(Error::UnknownSymbolCode(symbol_code.to_owned())),
+    };
+
+    Ok(WmoCode::try_from(value)?)
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Invalid WMO code: {0}")]
+    InvalidWmoCode(#[from] wmo_code::Error),
+
+    #[error("The symbol code \"{0}\" is not known")]
+    UnknownSymbolCode(String),
+
+    #[error("The obtained direction is invalid: {0}")]
+    InvalidBearing(#[from] bearing::Error),
+
+    #[error("The obtained percentage value is invalid: {0}")]
+    InvalidPercentage(#[from] percentage::Error),
+
+    #[error("The obtained time is invalid: {0}")]
+    InvalidTime(#[from] date::Error),
+
+    #[error("The MET Norway API returned no forecast")]
+    EmptyTimeSeries,
+
+    #[error("The MET Norway API could not be called successfully: {0}")]
+    ApiCall(Arc<reqwasm::Error>),
+
+    #[error("The MET Norway API could not be reached: {0}")]
+    Request(#[from] http::Error),
+
+    #[error("The MET Norway API responded with an error: {0}")]
+    Response(#[from] http::ResponseError),
+}
+
+impl Error {
+    pub fn is_offline(&self) -> bool {
+        matches!(self, Error::Request(http::Error::Offline))
+    }
+}
+
+impl From<reqwasm::Error> for Error {
+    fn from(error: reqwasm::Error) -> Self {
+        Error::ApiCall(Arc::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::{Bearing, CompassDirection, CompassResolution, Date, Precipitation, Speed, Temperature, WeatherRegistry, WmoCode};
+    use std::time::Duration;
+    use crate::http::{Response, ResponseError};
+    use crate::met_norway::{api_response, convert, wmo_code_from_symbol_code, Error, MetNorway};
+    use crate::provider::{ForecastDays, WeatherProvider};
+    use crate::test_util::coordinates;
+
+    const RESPONSE: &str = r#"{"properties": {"timeseries": [
+        {"time": "2024-02-10T22:00:00Z", "data": {
+            "instant": {"details": {"air_pressure_at_sea_level": 1012.0, "air_temperature": 3.0, "relative_humidity": 80.4, "wind_from_direction": 270.0, "wind_speed": 4.0}},
+            "next_1_hours": {"summary": {"symbol_code": "clearsky_night"}, "details": {"precipitation_amount": 0.0}},
+            "next_6_hours": {"summary": {"symbol_code": "lightrain"}, "details": {"precipitation_amount": 1.5}},
+            "next_12_hours": {"summary": {"symbol_code": "rain"}}
+        }},
+        {"time": "2024-02-10T23:00:00Z", "data": {
+            "instant": {"details": {"air_pressure_at_sea_level": 1011.0, "air_temperature": 1.0, "relative_humidity": 85.0, "wind_from_direction": 260.0, "wind_speed": 6.0}},
+            "next_1_hours": {"summary": {"symbol_code": "lightrain_night"}, "details": {"precipitation_amount": 0.4}}
+        }},
+        {"time": "2024-02-11T00:00:00Z", "data": {
+            "instant": {"details": {"air_pressure_at_sea_level": 1010.0, "air_temperature": 0.5, "relative_humidity": 90.0, "wind_from_direction": 10.0, "wind_speed": 2.0}},
+            "next_6_hours": {"summary": {"symbol_code": "heavysnowshowersandthunder_night"}, "details": {"precipitation_amount": 5.0}}
+        }},
+        {"time": "2024-02-11T06:00:00Z", "data": {
+            "instant": {"details": {"air_pressure_at_sea_level": 1009.0, "air_temperature": 2.5, "relative_humidity": 70.0, "wind_from_direction": 350.0, "wind_speed": 8.0}},
+            "next_6_hours": {"summary": {"symbol_code": "partlycloudy_day"}, "details": {"precipitation_amount": 0.0}}
+        }}
+    ]}}"#;
+
+    #[test]
+    fn symbol_codes() {
+        let wmo_code = |value| WmoCode::try_from(value).unwrap();
+
+        assert_eq!(wmo_code_from_symbol_code("clearsky_day").unwrap(), wmo_code(0));
+        assert_eq!(wmo_code_from_symbol_code("clearsky_polartwilight").unwrap(), wmo_code(0));
+        assert_eq!(wmo_code_from_symbol_code("cloudy").unwrap(), wmo_code(3));
+        assert_eq!(wmo_code_from_symbol_code("heavysnowshowers_night").unwrap(), wmo_code(86));
+        assert_eq!(wmo_code_from_symbol_code("lightssleetshowersandthunder_day").unwrap(), wmo_code(95));
+        assert_eq!(wmo_code_from_symbol_code("heavyrainandthunder").unwrap(), wmo_code(97));
+        assert!(wmo_code_from_symbol_code("meatballs").is_err());
+    }
+
+    #[test]
+    fn all_mapped_codes_are_registered() {
+        for symbol_code in ["clearsky", "fair", "partlycloudy", "cloudy", "fog", "lightrain", "rain", "heavyrain",
+                            "lightsleet", "sleet", "heavysleet", "lightsnow", "snow", "heavysnow",
+                            "lightrainshowers", "rainshowers", "heavyrainshowers", "lightsleetshowers",
+                            "sleetshowers", "heavysleetshowers", "lightsnowshowers", "snowshowers",
+                            "heavysnowshowers", "rainandthunder", "heavyrainandthunder"] {
+            WeatherRegistry::get(wmo_code_from_symbol_code(symbol_code).unwrap(), true);
+        }
+    }
+
+    #[test]
+    fn max_forecast_days() {
+        assert_eq!(u8::from(MetNorway::default().max_forecast_days()), 9);
+    }
+
+    #[test]
+    fn convert_response() {
+        let api_response = serde_json::from_str::<api_response::Response>(RESPONSE).unwrap();
+        let oslo = coordinates(59.91, 10.75);
+        let weather_data = convert(oslo, api_response, ForecastDays::default()).unwrap();
+
+        assert_eq!(weather_data.current_temperature, Temperature::Celsius(3.));
+        assert_eq!(weather_data.current_wind_speed, Speed::MetersPerSecond(4.));
+        assert_eq!(weather_data.current_wind_direction, Bearing::try_from(270.).unwrap());
+        assert_eq!(&*weather_data.current_weather.icon_path, "assets/icons/moon.svg");
+        assert_eq!(weather_data.hourly_forecast.len(), 2);
+        assert_eq!(weather_data.hourly_forecast[1].precipitation, Precipitation::Millimeters(0.4));
+
+        let days = weather_data.daily_forecast;
+        assert_eq!(days.len(), 2);
+
+        assert_eq!(days[0].date, Date::new(2024, 2, 10).unwrap());
+        assert_eq!(days[0].max_temperature, Temperature::Celsius(3.));
+        assert_eq!(days[0].min_temperature, Temperature::Celsius(1.));
+        assert_eq!(days[0].precipitation_sum, Precipitation::Millimeters(0.4));
+        assert_eq!(days[0].max_wind_speed, Speed::MetersPerSecond(6.));
+        assert_eq!(days[0].dominant_wind_direction.compass_direction(CompassResolution::EightPoints), CompassDirection::W);
+        assert_eq!(days[0].sunrise.unwrap().time_of_day(), "07:10");
+        assert_eq!(days[0].sunset.unwrap().time_of_day(), "15:54");
+
+        assert_eq!(days[1].date, Date::new(2024, 2, 11).unwrap());
+        assert_eq!(days[1].precipitation_sum, Precipitation::Millimeters(5.));
+        assert_eq!(days[1].dominant_wind_direction.compass_direction(CompassResolution::EightPoints), CompassDirection::N);
+        assert_eq!(days[1].weather.icon_path, WeatherRegistry::get(WmoCode::try_from(2).unwrap(), true).icon_path);
+    }
+
+    #[test]
+    fn error_responses() {
+        let parse = |status, retry_after: Option<&str>, body: &str| -> Result<api_response::Response, Error> {
+            let response = Response { status, retry_after: retry_after.map(str::to_string), body: body.to_string() };
+            Ok(response.parse()?)
+        };
+
+        assert!(matches!(parse(403, None, "<html><body>403 Forbidden</body></html>"), Err(Error::Response(ResponseError::Forbidden))));
+        assert!(matches!(parse(404, None, "Not Found"), Err(Error::Response(ResponseError::UnexpectedStatus { status: 404 }))));
+        assert!(matches!(parse(200, None, "<html></html>"), Err(Error::Response(ResponseError::InvalidBody(_)))));
+
+        match parse(429, Some("120"), "Too Many Requests") {
+            Err(Error::Response(ResponseError::RateLimited { retry_after, .. })) => assert_eq!(retry_after, Some(Duration::from_secs(120))),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+}
@@ -1,60 +1,144 @@
 use std::sync::Arc;
-use crate::data::{compass_direction, CompassDirection, Coordinates, Percentage, percentage, Pressure, Speed, Temperature, Weather, wmo_code, WmoCode};
+use crate::data::{bearing, coordinates, date, Bearing, Coordinates, DateRange, DateTime, Latitude, Longitude, Percentage, percentage, Precipitation, Pressure, Speed, Temperature, wmo_code, WmoCode};
 use crate::data::weather::WeatherRegistry;
+use crate::http::{self, RequestPolicy};
+use crate::provider::{self, DailyForecast, ForecastDays, HistoricalWeatherData, HistoryRange, HourlyForecast, WeatherArchive, WeatherData, WeatherProvider, HOURLY_FORECAST_HOURS};
+use crate::util::LocalBoxFuture;
 
-#[derive(Clone, Debug)]
-pub struct WeatherData {
-    pub current_weather: Weather,
-    pub current_temperature: Temperature,
-    pub current_wind_speed: Speed,
-    pub current_wind_direction: CompassDirection,
-    pub current_pressure: Pressure,
-    pub current_humidity: Percentage,
-}
+const CURRENT_VARIABLES: &str = "is_day,weather_code,temperature_2m,wind_speed_10m,wind_direction_10m,pressure_msl,relative_humidity_2m";
+const HOURLY_VARIABLES: &str = "is_day,weather_code,temperature_2m,wind_speed_10m,wind_direction_10m,pressure_msl,relative_humidity_2m,precipitation";
+const DAILY_VARIABLES: &str = "weather_code,temperature_2m_max,temperature_2m_min,precipitation_sum,wind_speed_10m_max,wind_direction_10m_dominant,sunrise,sunset";
 
 mod api_response {
     use serde::Deserialize;
 
     #[derive(Debug, Deserialize)]
     pub struct Current {
+        pub is_day: u8,
         pub weather_code: u8,
         pub temperature_2m: f32,
         pub wind_speed_10m: f32,
         pub wind_direction_10m: f32,
-        pub surface_pressure: f32,
+        pub pressure_msl: f32,
         pub relative_humidity_2m: u8,
     }
 
+    /// Values may be missing, e.g. for the most recent days of the archive.
+    #[derive(Debug, Deserialize)]
+    pub struct Hourly {
+        pub time: Vec<String>,
+        pub is_day: Vec<Option<u8>>,
+        pub weather_code: Vec<Option<u8>>,
+        pub temperature_2m: Vec<Option<f32>>,
+        pub wind_speed_10m: Vec<Option<f32>>,
+        pub wind_direction_10m: Vec<Option<f32>>,
+        pub pressure_msl: Vec<Option<f32>>,
+        pub relative_humidity_2m: Vec<Option<u8>>,
+        pub precipitation: Vec<Option<f32>>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Daily {
+        pub time: Vec<String>,
+        pub weather_code: Vec<Option<u8>>,
+        pub temperature_2m_max: Vec<Option<f32>>,
+        pub temperature_2m_min: Vec<Option<f32>>,
+        pub precipitation_sum: Vec<Option<f32>>,
+        pub wind_speed_10m_max: Vec<Option<f32>>,
+        pub wind_direction_10m_dominant: Vec<Option<f32>>,
+        pub sunrise: Vec<Option<String>>,
+        pub sunset: Vec<Option<String>>,
+    }
+
     #[derive(Debug, Deserialize)]
     pub struct Response {
+        pub latitude: f64,
+        pub longitude: f64,
+        pub utc_offset_seconds: i32,
         pub current: Current,
+        pub hourly: Hourly,
+        pub daily: Daily,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct ArchiveResponse {
+        pub hourly: Hourly,
+        pub daily: Daily,
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OpenMeteo {
+    pub request_policy: RequestPolicy,
+}
+
+impl WeatherProvider for OpenMeteo {
+    fn name(&self) -> &'static str {
+        "Open-Meteo"
+    }
+
+    fn attribution_url(&self) -> &'static str {
+        "https://open-meteo.com/"
+    }
+
+    fn fetch<'a>(&'a self,
+                 coordinates: Coordinates,
+                 forecast_days: ForecastDays) -> LocalBoxFuture<'a, Result<WeatherData, provider::Error>> {
+        Box::pin(async move {
+            Ok(call_api(coordinates, forecast_days, &self.request_policy).await?)
+        })
+    }
+}
+
+impl WeatherArchive for OpenMeteo {
+    fn name(&self) -> &'static str {
+        "Open-Meteo"
+    }
+
+    fn attribution_url(&self) -> &'static str {
+        "https://open-meteo.com/"
+    }
+
+    fn fetch_history<'a>(&'a self,
+                         coordinates: Coordinates,
+                         history_range: HistoryRange) -> LocalBoxFuture<'a, Result<HistoricalWeatherData, provider::Error>> {
+        Box::pin(async move {
+            Ok(call_archive_api(coordinates, history_range.into(), &self.request_policy).await?)
+        })
     }
 }
 
-pub async fn call_api(weather_registry: &WeatherRegistry,
-                      coordinates: Coordinates) -> Result<WeatherData, Error> {
+pub async fn call_api(coordinates: Coordinates,
+                      forecast_days: ForecastDays,
+                      request_policy: &RequestPolicy) -> Result<WeatherData, Error> {
 
     let url = format!(
-        "https://api.open-meteo.com/v1/forecast?latitude={}&longitude={}&current=weather_code,temperature_2m,wind_speed_10m,wind_direction_10m,surface_pressure,relative_humidity_2m",
-        f32::from(coordinates.latitude),
-        f32::from(coordinates.longitude),
+        "https://api.open-meteo.com/v1/forecast?latitude={}&longitude={}&current={}&hourly={}&forecast_hours={}&daily={}&forecast_days={}&timezone=auto",
+        f64::from(coordinates.latitude),
+        f64::from(coordinates.longitude),
+        CURRENT_VARIABLES,
+        HOURLY_VARIABLES,
+        HOURLY_FORECAST_HOURS,
+        DAILY_VARIABLES,
+        u8::from(forecast_days),
     );
 
-    let api_response = reqwasm::http::Request::get(&url)
-        .send()
+    let api_response = http::get(&url, request_policy)
         .await?
-        .json::<api_response::Response>()
-        .await?;
+        .parse::<api_response::Response>()?;
 
     let current_wmo_code = WmoCode::try_from(api_response.current.weather_code)?;
-    let current_weather = weather_registry.get(current_wmo_code);
+    let current_weather = WeatherRegistry::get(current_wmo_code, api_response.current.is_day == 1);
 
     let current_temperature = Temperature::Celsius(api_response.current.temperature_2m);
     let current_wind_speed = Speed::KilometersPerHour(api_response.current.wind_speed_10m);
-    let current_wind_direction = CompassDirection::from_degrees(api_response.current.wind_direction_10m)?;
-    let current_pressure = Pressure::HectoPascal(api_response.current.surface_pressure);
+    let current_wind_direction = Bearing::try_from(api_response.current.wind_direction_10m)?;
+    let current_pressure = Pressure::HectoPascal(api_response.current.pressure_msl);
     let current_humidity = Percentage::try_from(api_response.current.relative_humidity_2m)?;
 
+    let hourly_forecast = convert_hourly(api_response.hourly)?;
+    let daily_forecast = convert_daily(api_response.daily)?;
+
     Ok(WeatherData {
         current_weather,
         current_temperature,
@@ -62,9 +146,137 @@ pub async fn call_api(weather_registry: &WeatherRegistry,
         current_wind_direction,
         current_pressure,
         current_humidity,
+        hourly_forecast,
+        daily_forecast,
+        utc_offset_seconds: api_response.utc_offset_seconds,
+        grid_coordinates: Some(Coordinates {
+            latitude: Latitude::try_from(api_response.latitude)?,
+            longitude: Longitude::try_from(api_response.longitude)?,
+        }),
     })
 }
 
+/// Looks up past observations, based on reanalysis data.
+/// The most recent days are only available after a delay of a few days.
+pub async fn call_archive_api(coordinates: Coordinates,
+                              date_range: DateRange,
+                              request_policy: &RequestPolicy) -> Result<HistoricalWeatherData, Error> {
+
+    let url = format!(
+        "https://archive-api.open-meteo.com/v1/archive?latitude={}&longitude={}&start_date={}&end_date={}&hourly={}&daily={}&timezone=auto",
+        f64::from(coordinates.latitude),
+        f64::from(coordinates.longitude),
+        date_range.start(),
+        date_range.end(),
+        HOURLY_VARIABLES,
+        DAILY_VARIABLES,
+    );
+
+    let api_response = http::get(&url, request_policy)
+        .await?
+        .parse::<api_response::ArchiveResponse>()?;
+
+    Ok(HistoricalWeatherData {
+        hourly: convert_hourly(api_response.hourly)?,
+        daily: convert_daily(api_response.daily)?,
+    })
+}
+
+/// Hours with missing values are skipped.
+fn convert_hourly(hourly: api_response::Hourly) -> Result<Vec<HourlyForecast>, Error> {
+
+    let hour_count = hourly.time.len();
+
+    let series_lengths = [
+        hourly.is_day.len(),
+        hourly.weather_code.len(),
+        hourly.temperature_2m.len(),
+        hourly.wind_speed_10m.len(),
+        hourly.wind_direction_10m.len(),
+        hourly.pressure_msl.len(),
+        hourly.relative_humidity_2m.len(),
+        hourly.precipitation.len(),
+    ];
+
+    if series_lengths.iter().any(|&length| length != hour_count) {
+        return Err(Error::InconsistentTimeSeries);
+    }
+
+    (0..hour_count).map(|i| {
+        let (Some(is_day), Some(weather_code), Some(temperature), Some(wind_speed), Some(wind_direction), Some(pressure), Some(humidity), Some(precipitation)) = (
+            hourly.is_day[i],
+            hourly.weather_code[i],
+            hourly.temperature_2m[i],
+            hourly.wind_speed_10m[i],
+            hourly.wind_direction_10m[i],
+            hourly.pressure_msl[i],
+            hourly.relative_humidity_2m[i],
+            hourly.precipitation[i],
+        ) else {
+            return Ok(None);
+        };
+
+        Ok(Some(HourlyForecast {
+            time: hourly.time[i].parse()?,
+            weather: WeatherRegistry::get(WmoCode::try_from(weather_code)?, is_day == 1),
+            temperature: Temperature::Celsius(temperature),
+            wind_speed: Speed::KilometersPerHour(wind_speed),
+            wind_direction: Bearing::try_from(wind_direction)?,
+            pressure: Pressure::HectoPascal(pressure),
+            humidity: Percentage::try_from(humidity)?,
+            precipitation: Precipitation::Millimeters(precipitation),
+        }))
+    }).filter_map(Result::transpose).collect()
+}
+
+/// Days with missing values are skipped, only the sunrise and sunset may be missing.
+fn convert_daily(daily: api_response::Daily) -> Result<Vec<DailyForecast>, Error> {
+
+    let day_count = daily.time.len();
+
+    let series_lengths = [
+        daily.weather_code.len(),
+        daily.temperature_2m_max.len(),
+        daily.temperature_2m_min.len(),
+        daily.precipitation_sum.len(),
+        daily.wind_speed_10m_max.len(),
+        daily.wind_direction_10m_dominant.len(),
+        daily.sunrise.len(),
+        daily.sunset.len(),
+    ];
+
+    if series_lengths.iter().any(|&length| length != day_count) {
+        return Err(Error::InconsistentTimeSeries);
+    }
+
+    let parse_optional = |time: &Option<String>| time.as_deref().map(str::parse::<DateTime>).transpose();
+
+    (0..day_count).map(|i| {
+        let (Some(weather_code), Some(max_temperature), Some(min_temperature), Some(precipitation_sum), Some(max_wind_speed), Some(dominant_wind_direction)) = (
+            daily.weather_code[i],
+            daily.temperature_2m_max[i],
+            daily.temperature_2m_min[i],
+            daily.precipitation_sum[i],
+            daily.wind_speed_10m_max[i],
+            daily.wind_direction_10m_dominant[i],
+        ) else {
+            return Ok(None);
+        };
+
+        Ok(Some(DailyForecast {
+            date: daily.time[i].parse()?,
+            weather: WeatherRegistry::get(WmoCode::try_from(weather_code)?, true),
+            max_temperature: Temperature::Celsius(max_temperature),
+            min_temperature: Temperature::Celsius(min_temperature),
+            precipitation_sum: Precipitation::Millimeters(precipitation_sum),
+            max_wind_speed: Speed::KilometersPerHour(max_wind_speed),
+            dominant_wind_direction: Bearing::try_from(dominant_wind_direction)?,
+            sunrise: parse_optional(&daily.sunrise[i])?,
+            sunset: parse_optional(&daily.sunset[i])?,
+        }))
+    }).filter_map(Result::transpose).collect()
+}
+
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum Error {
     #[error("Invalid WMO code: {0}")]
@@ -74,13 +286,34 @@ pub enum Error {
     WmoCodeNotRegistered(WmoCode),
 
     #[error("The obtained direction is invalid: {0}")]
-    InvalidCompassDirection(#[from] compass_direction::Error),
+    InvalidBearing(#[from] bearing::Error),
 
     #[error("The obtained percentage value is invalid: {0}")]
     InvalidPercentage(#[from] percentage::Error),
 
+    #[error("The obtained grid point is invalid: {0}")]
+    InvalidCoordinates(#[from] coordinates::Error),
+
+    #[error("The obtained time is invalid: {0}")]
+    InvalidTime(#[from] date::Error),
+
+    #[error("The time series obtained from the Open-Meteo API have different lengths")]
+    InconsistentTimeSeries,
+
     #[error("The Open-Meteo API could not be called successfully: {0}")]
     ApiCall(Arc<reqwasm::Error>),
+
+    #[error("The Open-Meteo API could not be reached: {0}")]
+    Request(#[from] http::Error),
+
+    #[error("The Open-Meteo API responded with an error: {0}")]
+    Response(#[from] http::ResponseError),
+}
+
+impl Error {
+    pub fn is_offline(&self) -> bool {
+        matches!(self, Error::Request(http::Error::Offline))
+    }
 }
 
 impl From<reqwasm::Error> for Error {
@@ -88,3 +321,129 @@ impl From<reqwasm::Error> for Error {
         Error::ApiCall(Arc::new(error))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::data::Temperature;
+    use serde::de::DeserializeOwned;
+    use crate::http::{Response, ResponseError};
+    use crate::open_meteo::{api_response, convert_daily, convert_hourly, Error};
+
+    fn parse_response<T: DeserializeOwned>(status: u16, retry_after: Option<&str>, body: &str) -> Result<T, Error> {
+        let response = Response { status, retry_after: retry_after.map(str::to_string), body: body.to_string() };
+        Ok(response.parse()?)
+    }
+
+    #[test]
+    fn rejected_request() {
+        let body = r#"{"error": true, "reason": "Latitude must be in range of -90 to 90°. Given: 91.0."}"#;
+
+        match parse_response::<api_response::Response>(400, None, body) {
+            Err(Error::Response(ResponseError::Rejected { reason })) => assert_eq!(reason, "Latitude must be in range of -90 to 90°. Given: 91.0."),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn rate_limited_request() {
+        match parse_response::<api_response::Response>(429, Some("60"), "") {
+            Err(error @ Error::Response(ResponseError::RateLimited { retry_after: Some(retry_after), .. })) => {
+                assert_eq!(retry_after, Duration::from_secs(60));
+                assert_eq!(error.to_string(), "The Open-Meteo API responded with an error: The rate limit has been exceeded, retry after 60 seconds");
+            },
+            result => panic!("Unexpected result: {:?}", result),
+        }
+
+        assert!(matches!(
+            parse_response::<api_response::Response>(429, Some("Wed, 21 Oct 2015 07:28:00 GMT"), ""),
+            Err(Error::Response(ResponseError::RateLimited { retry_after: None, .. })),
+        ));
+    }
+
+    #[test]
+    fn unexpected_status_and_body() {
+        assert!(matches!(parse_response::<api_response::Response>(404, None, "Not Found"), Err(Error::Response(ResponseError::UnexpectedStatus { status: 404 }))));
+        assert!(matches!(parse_response::<api_response::Response>(200, None, "{}"), Err(Error::Response(ResponseError::InvalidBody(_)))));
+    }
+
+    #[test]
+    fn archive_response() {
+        let body = r#"{
+            "latitude": 52.52,
+            "longitude": 13.419998,
+            "hourly": {
+                "time": ["2024-01-15T00:00", "2024-01-15T01:00"],
+                "is_day": [0, 0],
+                "weather_code": [3, 71],
+                "temperature_2m": [-1.2, -1.5],
+                "wind_speed_10m": [12.4, 11.9],
+                "wind_direction_10m": [250.0, 245.0],
+                "pressure_msl": [1008.1, 1008.4],
+                "relative_humidity_2m": [91, 93],
+                "precipitation": [0.0, 0.3]
+            },
+            "daily": {
+                "time": ["2024-01-15"],
+                "weather_code": [73],
+                "temperature_2m_max": [0.4],
+                "temperature_2m_min": [-2.8],
+                "precipitation_sum": [4.2],
+                "wind_speed_10m_max": [18.7],
+                "wind_direction_10m_dominant": [248.0],
+                "sunrise": ["2024-01-15T08:09"],
+                "sunset": ["2024-01-15T16:22"]
+            }
+        }"#;
+
+        let api_response = parse_response::<api_response::ArchiveResponse>(200, None, body).unwrap();
+
+        let hourly = convert_hourly(api_response.hourly).unwrap();
+        let daily = convert_daily(api_response.daily).unwrap();
+
+        assert_eq!(hourly.len(), 2);
+        assert_eq!(hourly[1].time.to_string(), "2024-01-15T01:00");
+        assert_eq!(hourly[1].temperature, Temperature::Celsius(-1.5));
+        assert_eq!(daily.len(), 1);
+        assert_eq!(daily[0].min_temperature, Temperature::Celsius(-2.8));
+        assert_eq!(daily[0].sunset.unwrap().time_of_day(), "16:22");
+    }
+
+    #[test]
+    fn missing_values() {
+        let body = r#"{
+            "hourly": {
+                "time": ["2024-01-15T00:00", "2024-01-15T01:00", "2024-01-15T02:00"],
+                "is_day": [0, 0, 0],
+                "weather_code": [3, 71, null],
+                "temperature_2m": [-1.2, null, null],
+                "wind_speed_10m": [12.4, 11.9, null],
+                "wind_direction_10m": [250.0, 245.0, null],
+                "pressure_msl": [1008.1, 1008.4, null],
+                "relative_humidity_2m": [91, 93, null],
+                "precipitation": [0.0, 0.3, null]
+            },
+            "daily": {
+                "time": ["2024-01-15", "2024-01-16"],
+                "weather_code": [73, null],
+                "temperature_2m_max": [0.4, null],
+                "temperature_2m_min": [-2.8, null],
+                "precipitation_sum": [4.2, null],
+                "wind_speed_10m_max": [18.7, null],
+                "wind_direction_10m_dominant": [248.0, null],
+                "sunrise": ["2024-01-15T08:09", "2024-01-16T08:08"],
+                "sunset": ["2024-01-15T16:22", "2024-01-16T16:24"]
+            }
+        }"#;
+
+        let api_response = parse_response::<api_response::ArchiveResponse>(200, None, body).unwrap();
+
+        let hourly = convert_hourly(api_response.hourly).unwrap();
+        let daily = convert_daily(api_response.daily).unwrap();
+
+        assert_eq!(hourly.len(), 1);
+        assert_eq!(hourly[0].time.to_string(), "2024-01-15T00:00");
+        assert_eq!(daily.len(), 1);
+        assert_eq!(daily[0].date.to_string(), "2024-01-15");
+    }
+}
@@ -0,0 +1,243 @@
+use std::cell::RefCell;
+use serde::{Deserialize, Serialize};
+use crate::data::{Bearing, Coordinates, Date, DateRange, DateTime, Percentage, Precipitation, Pressure, Speed, Temperature, Weather};
+use crate::met_norway;
+use crate::open_meteo;
+use crate::util::LocalBoxFuture;
+
+pub const HOURLY_FORECAST_HOURS: u8 = 24;
+
+pub const MIN_FORECAST_DAYS: u8 = 7;
+pub const MAX_FORECAST_DAYS: u8 = 16;
+
+pub const MAX_HISTORY_DAYS: u32 = 31;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ForecastDays(u8);
+
+impl Default for ForecastDays {
+    fn default() -> Self {
+        ForecastDays(MIN_FORECAST_DAYS)
+    }
+}
+
+impl TryFrom<u8> for ForecastDays {
+    type Error = ForecastDaysError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if (MIN_FORECAST_DAYS..=MAX_FORECAST_DAYS).contains(&value) {
+            Ok(ForecastDays(value))
+        } else {
+            Err(ForecastDaysError::InvalidValue(value))
+        }
+    }
+}
+
+impl From<ForecastDays> for u8 {
+    fn from(value: ForecastDays) -> Self {
+        value.0
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum ForecastDaysError {
+    #[error("The number of forecast days must lie in [{MIN_FORECAST_DAYS}, {MAX_FORECAST_DAYS}], but it is {0}")]
+    InvalidValue(u8),
+}
+
+/// Range of past dates to look up, limited to [`MAX_HISTORY_DAYS`] to keep the hourly series manageable.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HistoryRange(DateRange);
+
+impl TryFrom<DateRange> for HistoryRange {
+    type Error = HistoryRangeError;
+
+    fn try_from(value: DateRange) -> Result<Self, Self::Error> {
+        if value.day_count() <= MAX_HISTORY_DAYS {
+            Ok(HistoryRange(value))
+        } else {
+            Err(HistoryRangeError::TooLong(value.day_count()))
+        }
+    }
+}
+
+impl From<HistoryRange> for DateRange {
+    fn from(value: HistoryRange) -> Self {
+        value.0
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum HistoryRangeError {
+    #[error("At most {MAX_HISTORY_DAYS} days can be looked up at once, but {0} were requested")]
+    TooLong(u32),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WeatherData {
+    pub current_weather: Weather,
+    pub current_temperature: Temperature,
+    pub current_wind_speed: Speed,
+    pub current_wind_direction: Bearing,
+    /// Reduced to mean sea level, the only pressure all providers report
+    pub current_pressure: Pressure,
+    pub current_humidity: Percentage,
+    pub hourly_forecast: Vec<HourlyForecast>,
+    pub daily_forecast: Vec<DailyForecast>,
+
+    /// Offset of the local time used for all times above, missing in data cached by earlier versions
+    #[serde(default)]
+    pub utc_offset_seconds: i32,
+
+    /// Position of the model grid point the data was calculated for, if the provider reports it
+    #[serde(default)]
+    pub grid_coordinates: Option<Coordinates>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HourlyForecast {
+    pub time: DateTime,
+    pub weather: Weather,
+    pub temperature: Temperature,
+    pub wind_speed: Speed,
+    pub wind_direction: Bearing,
+    /// Reduced to mean sea level
+    pub pressure: Pressure,
+    pub humidity: Percentage,
+    pub precipitation: Precipitation,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DailyForecast {
+    pub date: Date,
+    pub weather: Weather,
+    pub max_temperature: Temperature,
+    pub min_temperature: Temperature,
+    pub precipitation_sum: Precipitation,
+    pub max_wind_speed: Speed,
+    pub dominant_wind_direction: Bearing,
+    pub sunrise: Option<DateTime>,
+    pub sunset: Option<DateTime>,
+}
+
+/// Observed weather of the past, in the same shape as a forecast.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HistoricalWeatherData {
+    pub hourly: Vec<HourlyForecast>,
+    pub daily: Vec<DailyForecast>,
+}
+
+pub trait WeatherProvider {
+    fn name(&self) -> &'static str;
+
+    fn attribution_url(&self) -> &'static str;
+
+    /// The longest forecast the provider covers, requests are limited to it.
+    fn max_forecast_days(&self) -> ForecastDays {
+        ForecastDays(MAX_FORECAST_DAYS)
+    }
+
+    fn fetch<'a>(&'a self,
+                 coordinates: Coordinates,
+                 forecast_days: ForecastDays) -> LocalBoxFuture<'a, Result<WeatherData, Error>>;
+}
+
+pub trait WeatherArchive {
+    fn name(&self) -> &'static str;
+
+    fn attribution_url(&self) -> &'static str;
+
+    fn fetch_history<'a>(&'a self,
+                         coordinates: Coordinates,
+                         history_range: HistoryRange) -> LocalBoxFuture<'a, Result<HistoricalWeatherData, Error>>;
+}
+
+/// Provider answering every request with the same in-memory result, e.g. for tests.
+#[derive(Debug)]
+pub struct FakeProvider {
+    result: Result<WeatherData, Error>,
+    requests: RefCell<Vec<(Coordinates, ForecastDays)>>,
+}
+
+impl FakeProvider {
+    pub fn new(result: Result<WeatherData, Error>) -> Self {
+        FakeProvider { result, requests: RefCell::default() }
+    }
+
+    pub fn requests(&self) -> Vec<(Coordinates, ForecastDays)> {
+        self.requests.borrow().clone()
+    }
+}
+
+impl WeatherProvider for FakeProvider {
+    fn name(&self) -> &'static str {
+        "Fake"
+    }
+
+    fn attribution_url(&self) -> &'static str {
+        "about:blank"
+    }
+
+    fn fetch<'a>(&'a self,
+                 coordinates: Coordinates,
+                 forecast_days: ForecastDays) -> LocalBoxFuture<'a, Result<WeatherData, Error>> {
+        self.requests.borrow_mut().push((coordinates, forecast_days));
+        let result = self.result.clone();
+        Box::pin(async move { result })
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    OpenMeteo(#[from] open_meteo::Error),
+
+    #[error(transparent)]
+    MetNorway(#[from] met_norway::Error),
+
+    #[error("{0}")]
+    Fake(String),
+}
+
+impl Error {
+    pub fn is_offline(&self) -> bool {
+        match self {
+            Error::OpenMeteo(error) => error.is_offline(),
+            Error::MetNorway(error) => error.is_offline(),
+            Error::Fake(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use crate::data::{Date, DateRange, Temperature};
+    use crate::provider::{Error, FakeProvider, ForecastDays, HistoryRange, WeatherProvider};
+    use crate::test_util::{coordinates, weather_data};
+
+    #[test]
+    fn fake_provider_returns_its_result() {
+        let coordinates = coordinates(52.5, 13.25);
+
+        let provider = FakeProvider::new(Ok(weather_data(20.)));
+        let weather_data = block_on(provider.fetch(coordinates, ForecastDays::default())).unwrap();
+
+        assert_eq!(weather_data.current_temperature, Temperature::Celsius(20.));
+        assert_eq!(provider.requests(), vec![(coordinates, ForecastDays::default())]);
+
+        let provider = FakeProvider::new(Err(Error::Fake("unavailable".to_owned())));
+        assert!(block_on(provider.fetch(coordinates, ForecastDays::default())).is_err());
+    }
+
+    #[test]
+    fn history_range_length() {
+        let range = |start: &str, end: &str| {
+            DateRange::new(start.parse::<Date>().unwrap(), end.parse::<Date>().unwrap()).unwrap()
+        };
+
+        assert!(HistoryRange::try_from(range("2024-01-01", "2024-01-01")).is_ok());
+        assert!(HistoryRange::try_from(range("2024-01-01", "2024-01-31")).is_ok());
+        assert!(HistoryRange::try_from(range("2024-01-01", "2024-02-01")).is_err());
+    }
+}
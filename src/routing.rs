@@ -0,0 +1,73 @@
+use crate::data::{Coordinates, Latitude, Longitude};
+
+/// Reads the coordinates from a query string like `?lat=52.52&lon=13.41`. Other parameters are ignored.
+pub fn parse_query(query: &str) -> Option<Coordinates> {
+    let mut latitude = None;
+    let mut longitude = None;
+
+    for parameter in query.trim_start_matches('?').split('&') {
+        let Some((key, value)) = parameter.split_once('=') else {
+            continue;
+        };
+
+        let value = urlencoding::decode(value).ok()?;
+
+        match key {
+            "lat" => latitude = Some(Latitude::try_from(value.parse::<f64>().ok()?).ok()?),
+            "lon" => longitude = Some(Longitude::try_from(value.parse::<f64>().ok()?).ok()?),
+            _ => {},
+        }
+    }
+
+    Some(Coordinates { latitude: latitude?, longitude: longitude? })
+}
+
+pub fn format_query(coordinates: Coordinates) -> String {
+    format!("?lat={}&lon={}", f64::from(coordinates.latitude), f64::from(coordinates.longitude))
+}
+
+/// Coordinates in the URL of the current page, if any.
+pub fn current_coordinates() -> Option<Coordinates> {
+    let search = web_sys::window()?.location().search().ok()?;
+    parse_query(&search)
+}
+
+/// Adds a history entry for the coordinates, unless the URL of the current page already refers to them.
+pub fn push_coordinates(coordinates: Coordinates) {
+    if current_coordinates() == Some(coordinates) {
+        return;
+    }
+
+    if let Some(history) = web_sys::window().and_then(|window| window.history().ok()) {
+        let _ = history.push_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&format_query(coordinates)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routing::{format_query, parse_query};
+    use crate::test_util::coordinates;
+
+    #[test]
+    fn parse_coordinates() {
+        assert_eq!(parse_query("?lat=52.5&lon=13.25"), Some(coordinates(52.5, 13.25)));
+        assert_eq!(parse_query("lon=-13.25&lat=-52.5"), Some(coordinates(-52.5, -13.25)));
+        assert_eq!(parse_query("?utm_source=mail&lat=52.5&lon=13.25&"), Some(coordinates(52.5, 13.25)));
+        assert_eq!(parse_query("?lat=%2D52.5&lon=13.25"), Some(coordinates(-52.5, 13.25)));
+    }
+
+    #[test]
+    fn parse_invalid_coordinates() {
+        assert_eq!(parse_query(""), None);
+        assert_eq!(parse_query("?lat=52.5"), None);
+        assert_eq!(parse_query("?lat=91&lon=13.25"), None);
+        assert_eq!(parse_query("?lat=north&lon=13.25"), None);
+    }
+
+    #[test]
+    fn format_and_parse() {
+        let coordinates = coordinates(-33.75, 151.5);
+        assert_eq!(format_query(coordinates), "?lat=-33.75&lon=151.5");
+        assert_eq!(parse_query(&format_query(coordinates)), Some(coordinates));
+    }
+}
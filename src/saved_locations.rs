@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+use crate::data::Coordinates;
+
+const STORAGE_KEY: &str = "stormy_skies.saved_locations";
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SavedLocation {
+    pub id: u32,
+    pub name: String,
+    pub coordinates: Coordinates,
+}
+
+/// Named locations in the order chosen by the user. The ids stay stable while locations are renamed or reordered.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct SavedLocations(Vec<SavedLocation>);
+
+impl SavedLocations {
+    pub fn locations(&self) -> &[SavedLocation] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn add(&mut self, name: String, coordinates: Coordinates) -> u32 {
+        let id = self.0.iter().map(|location| location.id + 1).max().unwrap_or(0);
+        self.0.push(SavedLocation { id, name, coordinates });
+        id
+    }
+
+    pub fn rename(&mut self, id: u32, name: String) {
+        if let Some(location) = self.0.iter_mut().find(|location| location.id == id) {
+            location.name = name;
+        }
+    }
+
+    pub fn remove(&mut self, id: u32) {
+        self.0.retain(|location| location.id != id);
+    }
+
+    pub fn move_up(&mut self, id: u32) {
+        if let Some(index) = self.position(id).filter(|&index| index > 0) {
+            self.0.swap(index - 1, index);
+        }
+    }
+
+    pub fn move_down(&mut self, id: u32) {
+        if let Some(index) = self.position(id).filter(|&index| index + 1 < self.0.len()) {
+            self.0.swap(index, index + 1);
+        }
+    }
+
+    /// Nearest first, the order of equally distant locations is kept.
+    pub fn sort_by_distance(&mut self, from: Coordinates) {
+        let mut by_distance = self.0.drain(..)
+            .map(|location| (f32::from(from.haversine_distance(&location.coordinates)), location))
+            .collect::<Vec<_>>();
+
+        by_distance.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        self.0 = by_distance.into_iter().map(|(_, location)| location).collect();
+    }
+
+    fn position(&self, id: u32) -> Option<usize> {
+        self.0.iter().position(|location| location.id == id)
+    }
+}
+
+/// Persists the saved locations in the `localStorage` of the browser, if it is accessible.
+#[derive(Clone, Debug, Default)]
+pub struct SavedLocationStore {
+    storage: Option<web_sys::Storage>,
+}
+
+impl SavedLocationStore {
+    pub fn with_local_storage() -> Self {
+        let storage = web_sys::window().and_then(|window| window.local_storage().ok().flatten());
+        SavedLocationStore { storage }
+    }
+
+    /// Corrupt or missing data results in an empty list rather than an error, as there is nothing to recover.
+    pub fn load(&self) -> SavedLocations {
+        self.storage.as_ref()
+            .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, saved_locations: &SavedLocations) {
+        let Some(storage) = self.storage.as_ref() else {
+            return;
+        };
+
+        if let Ok(json) = serde_json::to_string(saved_locations) {
+            let _ = storage.set_item(STORAGE_KEY, &json);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::saved_locations::SavedLocations;
+    use crate::test_util::coordinates;
+
+    fn names(saved_locations: &SavedLocations) -> Vec<&str> {
+        saved_locations.locations().iter().map(|location| location.name.as_str()).collect()
+    }
+
+    #[test]
+    fn edit_locations() {
+        let mut saved_locations = SavedLocations::default();
+        let home = saved_locations.add("Home".to_owned(), coordinates(52.5, 13.25));
+        let harbour = saved_locations.add("Harbour".to_owned(), coordinates(54.25, 10.5));
+        let office = saved_locations.add("Office".to_owned(), coordinates(48.25, 11.5));
+
+        saved_locations.move_up(office);
+        saved_locations.move_up(office);
+        saved_locations.move_up(office);
+        assert_eq!(names(&saved_locations), vec!["Office", "Home", "Harbour"]);
+
+        saved_locations.move_down(home);
+        saved_locations.move_down(home);
+        assert_eq!(names(&saved_locations), vec!["Office", "Harbour", "Home"]);
+
+        saved_locations.rename(harbour, "Marina".to_owned());
+        saved_locations.remove(office);
+        assert_eq!(names(&saved_locations), vec!["Marina", "Home"]);
+
+        let garden = saved_locations.add("Garden".to_owned(), coordinates(52.5, 13.5));
+        assert!(![home, harbour].contains(&garden));
+    }
+
+    #[test]
+    fn sort_by_distance() {
+        let mut saved_locations = SavedLocations::default();
+        saved_locations.add("Munich".to_owned(), coordinates(48.25, 11.5));
+        saved_locations.add("Kiel".to_owned(), coordinates(54.25, 10.5));
+        saved_locations.add("Potsdam".to_owned(), coordinates(52.5, 13.0));
+        saved_locations.add("Berlin".to_owned(), coordinates(52.5, 13.25));
+
+        saved_locations.sort_by_distance(coordinates(52.5, 13.5));
+        assert_eq!(names(&saved_locations), vec!["Berlin", "Potsdam", "Kiel", "Munich"]);
+    }
+
+    #[test]
+    fn locations_survive_serialization() {
+        let mut saved_locations = SavedLocations::default();
+        saved_locations.add("Home".to_owned(), coordinates(52.5, -13.25));
+
+        let json = serde_json::to_string(&saved_locations).unwrap();
+        assert_eq!(serde_json::from_str::<SavedLocations>(&json).unwrap(), saved_locations);
+    }
+}
@@ -0,0 +1,27 @@
+//! Constructors shared by the tests of several modules.
+
+use crate::data::{Bearing, Coordinates, Latitude, Longitude, Percentage, Pressure, Speed, Temperature, WeatherRegistry, WmoCode};
+use crate::provider::WeatherData;
+
+pub fn coordinates(latitude: f64, longitude: f64) -> Coordinates {
+    Coordinates {
+        latitude: Latitude::try_from(latitude).unwrap(),
+        longitude: Longitude::try_from(longitude).unwrap(),
+    }
+}
+
+/// Clear sky without any forecast.
+pub fn weather_data(temperature: f32) -> WeatherData {
+    WeatherData {
+        current_weather: WeatherRegistry::get(WmoCode::try_from(0).unwrap(), true),
+        current_temperature: Temperature::Celsius(temperature),
+        current_wind_speed: Speed::KilometersPerHour(10.),
+        current_wind_direction: Bearing::try_from(225.).unwrap(),
+        current_pressure: Pressure::HectoPascal(1013.),
+        current_humidity: Percentage::try_from(50).unwrap(),
+        hourly_forecast: Vec::new(),
+        daily_forecast: Vec::new(),
+        utc_offset_seconds: 0,
+        grid_coordinates: None,
+    }
+}
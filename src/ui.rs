@@ -1,122 +1,844 @@
-use std::ops::Deref;
 use std::rc::Rc;
 use leptos::*;
-use crate::data::{Coordinates, WeatherRegistry};
-use crate::open_meteo::{self, WeatherData};
+use crate::air_quality::{self, AirQuality, AirQualitySource};
+use crate::astronomy::{self, Crossing, Daylight, SolarPosition};
+use crate::cache::{CacheKey, CachedWeatherData, WeatherCache, REVALIDATION_RETRY_DELAY};
+use crate::data::{derived, AirQualityIndex, Bearing, CompassResolution, Concentration, Coordinates, Date, DateRange, DateTime, LengthUnit, Measurement, PrecipitationUnit, PressureUnit, SpeedUnit, TemperatureUnit, UnitSystem};
+use crate::geocoding::{self, Geocoder, Place};
+use crate::geolocation::{self, GeolocationSource};
+use crate::http;
+use crate::i18n::{Locale, LocaleStore, Text};
+use crate::marine::{self, MarineConditions, MarineSource, WaveComponent};
+use crate::routing;
+use crate::saved_locations::{SavedLocation, SavedLocationStore, SavedLocations};
+use crate::provider::{self, DailyForecast, ForecastDays, HistoricalWeatherData, HistoryRange, HourlyForecast, WeatherArchive, WeatherData, WeatherProvider, MIN_FORECAST_DAYS};
 use crate::util::{AlwaysEqual, NeverEqual};
 
-enum ApiCallState {
+enum ApiCallState<T> {
     NotCalled,
     ResponsePending,
-    Error(open_meteo::Error),
-    Responded(WeatherData),
+    Error(provider::Error),
+    Responded(T),
 }
 
-#[component]
-pub fn App(weather_registry: Rc<WeatherRegistry>) -> impl IntoView {
-    let (get_coordinates, set_coordinates) = create_signal::<Option<NeverEqual<Coordinates>>>(None);
+impl<T> From<Option<Option<Result<T, provider::Error>>>> for ApiCallState<T> {
+    fn from(value: Option<Option<Result<T, provider::Error>>>) -> Self {
+        match value {
+            None => ApiCallState::ResponsePending,
+            Some(None) => ApiCallState::NotCalled,
+            Some(Some(Err(error))) => ApiCallState::Error(error),
+            Some(Some(Ok(response))) => ApiCallState::Responded(response),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Mode {
+    Forecast,
+    History,
+    Dashboard,
+}
 
-    let source = move || (AlwaysEqual(weather_registry.clone()), get_coordinates());
+struct WeatherRequest {
+    weather_cache: Rc<WeatherCache>,
+    provider: Rc<dyn WeatherProvider>,
+    coordinates: Coordinates,
+    forecast_days: ForecastDays,
+}
 
-    async fn fetcher((weather_registry, coordinates): (AlwaysEqual<Rc<WeatherRegistry>>, Option<NeverEqual<Coordinates>>))
-                     -> Option<Result<WeatherData, open_meteo::Error>> {
-        let coordinates = coordinates?.into_inner();
-        let weather_registry = weather_registry.into_inner();
-        let weather_registry = weather_registry.deref();
-        let weather_data = open_meteo::call_api(weather_registry, coordinates).await;
-        Some(weather_data)
+impl WeatherRequest {
+    async fn fetch(&self) -> Result<WeatherData, provider::Error> {
+        self.provider.fetch(self.coordinates, self.forecast_days).await
     }
 
+    /// Serves cached data if present. Stale data is revalidated in the background, and again later if that fails.
+    async fn fetch_cached(self, revalidated: Trigger) -> Result<CachedWeatherData, provider::Error> {
+        let key = CacheKey::new(self.provider.name(), self.coordinates, self.forecast_days);
+        let now_millis = js_sys::Date::now();
+
+        if let Some(cached) = self.weather_cache.get(&key, now_millis) {
+            // Revalidating while offline would only fail, it is resumed once the browser is back online
+            if cached.is_stale && http::is_online() && self.weather_cache.begin_revalidation(&key, now_millis) {
+                spawn_local(async move {
+                    match self.fetch().await {
+                        Ok(weather_data) => {
+                            self.weather_cache.insert(key.clone(), weather_data, js_sys::Date::now());
+                            self.weather_cache.end_revalidation(&key);
+                        }
+                        Err(_) => {
+                            self.weather_cache.fail_revalidation(&key, js_sys::Date::now());
+                            set_timeout(move || revalidated.notify(), REVALIDATION_RETRY_DELAY);
+                        }
+                    }
+
+                    // Also after a failure, so that the notice tells that the data could not be refreshed
+                    revalidated.notify();
+                });
+            }
+
+            return Ok(cached);
+        }
+
+        let weather_data = self.fetch().await?;
+        self.weather_cache.insert(key, weather_data.clone(), now_millis);
+        Ok(CachedWeatherData { weather_data, fetched_at_millis: now_millis, is_stale: false, refresh_failed: false })
+    }
+}
+
+#[component]
+pub fn App(weather_cache: Rc<WeatherCache>,
+           providers: Vec<Rc<dyn WeatherProvider>>,
+           weather_archive: Rc<dyn WeatherArchive>,
+           air_quality_source: Rc<dyn AirQualitySource>,
+           marine_source: Rc<dyn MarineSource>,
+           geocoder: Rc<dyn Geocoder>,
+           geolocation_source: Rc<dyn GeolocationSource>,
+           saved_location_store: Rc<SavedLocationStore>,
+           locale_store: Rc<LocaleStore>) -> impl IntoView {
+    let (get_coordinates, set_coordinates) = create_signal(routing::current_coordinates().map(NeverEqual));
+    let (get_geolocation_error, set_geolocation_error) = create_signal::<Option<geolocation::Error>>(None);
+    let (get_forecast_days, set_forecast_days) = create_signal(ForecastDays::default());
+    let (get_unit_system, set_unit_system) = create_signal(UnitSystem::default());
+    let (get_provider_index, set_provider_index) = create_signal(0);
+    let (get_saved_locations, set_saved_locations) = create_signal(saved_location_store.load());
+    let show_dashboard = get_coordinates.get_untracked().is_none() && !get_saved_locations.get_untracked().is_empty();
+    let (get_mode, set_mode) = create_signal(if show_dashboard { Mode::Dashboard } else { Mode::Forecast });
+    let (get_history_range, set_history_range) = create_signal::<Option<HistoryRange>>(None);
+    let (get_is_online, set_is_online) = create_signal(http::is_online());
+    let (get_locale_override, set_locale_override) = create_signal(locale_store.load());
+    let get_locale = Signal::derive(move || get_locale_override().or_else(Locale::from_browser).unwrap_or_default());
+
+    create_effect(move |_| {
+        get_coordinates.track();
+        set_geolocation_error(None);
+    });
+
+    // Every location shown gets its own history entry, so that it can be bookmarked and navigated back to
+    create_effect(move |_| {
+        if let Some(coordinates) = get_coordinates() {
+            routing::push_coordinates(coordinates.into_inner());
+        }
+    });
+
+    window_event_listener(ev::popstate, move |_| set_coordinates(routing::current_coordinates().map(NeverEqual)));
+
+    // Choosing a location leaves the dashboard
+    create_effect(move |_| {
+        if get_coordinates().is_some() && get_mode.get_untracked() == Mode::Dashboard {
+            set_mode(Mode::Forecast);
+        }
+    });
+
+    create_effect(move |_| get_saved_locations.with(|saved_locations| saved_location_store.save(saved_locations)));
+    create_effect(move |_| locale_store.save(get_locale_override()));
+
+    let providers: Rc<[Rc<dyn WeatherProvider>]> = providers.into();
+    let get_provider = {
+        let providers = providers.clone();
+        Signal::derive(move || providers[get_provider_index()].clone())
+    };
+    let get_max_forecast_days = Signal::derive(move || get_provider().max_forecast_days());
+
+    // Switching to a provider with a shorter range shortens the forecast instead of silently returning fewer days
+    create_effect(move |_| {
+        let max_forecast_days = get_max_forecast_days();
+        if get_forecast_days.get_untracked() > max_forecast_days {
+            set_forecast_days(max_forecast_days);
+        }
+    });
+
+    // Notified once stale data has been revalidated in the background, so that the fresh data is loaded from the cache
+    let revalidated = create_trigger();
+
+    window_event_listener(ev::offline, move |_| set_is_online(false));
+    window_event_listener(ev::online, move |_| {
+        set_is_online(true);
+        revalidated.notify();
+    });
+
+    let source = move || {
+        revalidated.track();
+        (get_mode(), AlwaysEqual(get_provider()), get_provider_index(), get_coordinates(), get_forecast_days())
+    };
+
+    type Source = (Mode, AlwaysEqual<Rc<dyn WeatherProvider>>, usize, Option<NeverEqual<Coordinates>>, ForecastDays);
+
+    let fetcher = {
+        let weather_cache = weather_cache.clone();
+
+        move |(mode, provider, _, coordinates, forecast_days): Source| {
+            let weather_cache = weather_cache.clone();
+
+            async move {
+                if mode != Mode::Forecast {
+                    return None;
+                }
+
+                let coordinates = coordinates?.into_inner();
+                let provider = provider.into_inner();
+                let request = WeatherRequest { weather_cache, provider, coordinates, forecast_days };
+                Some(request.fetch_cached(revalidated).await)
+            }
+        }
+    };
+
     let weather_data_resource = create_local_resource(
         source,
         fetcher,
     );
 
-    let weather_data_state = move || {
-        match weather_data_resource.get() {
-            None => ApiCallState::ResponsePending,
-            Some(None) => ApiCallState::NotCalled,
-            Some(Some(Err(error))) => ApiCallState::Error(error),
-            Some(Some(Ok(response))) => ApiCallState::Responded(response),
+    let weather_data_state = move || ApiCallState::from(weather_data_resource.get());
+
+    let history_source = move || (get_mode(), get_coordinates(), get_history_range());
+
+    let history_fetcher = {
+        let weather_archive = weather_archive.clone();
+
+        move |(mode, coordinates, history_range): (Mode, Option<NeverEqual<Coordinates>>, Option<HistoryRange>)| {
+            let weather_archive = weather_archive.clone();
+
+            async move {
+                if mode != Mode::History {
+                    return None;
+                }
+
+                let coordinates = coordinates?.into_inner();
+                let history_range = history_range?;
+                Some(weather_archive.fetch_history(coordinates, history_range).await)
+            }
         }
     };
 
-    view! {
-        <Header set_coordinates=set_coordinates/>
-        <Main weather_data_state=weather_data_state/>
-        <Footer/>
-    }
-}
+    let history_resource = create_local_resource(history_source, history_fetcher);
+    let history_state = move || ApiCallState::from(history_resource.get());
 
-#[component]
-fn Header(set_coordinates: WriteSignal<Option<NeverEqual<Coordinates>>>) -> impl IntoView {
-    use crate::data::{Coordinates, Latitude, Longitude};
+    let air_quality_fetcher = move |(mode, coordinates): (Mode, Option<NeverEqual<Coordinates>>)| {
+        let air_quality_source = air_quality_source.clone();
 
-    let (get_latitude_text, set_latitude_text) = create_signal(String::default());
-    let (get_longitude_text, set_longitude_text) = create_signal(String::default());
+        async move {
+            if mode != Mode::Forecast {
+                return None;
+            }
 
-    let get_latitude = move || {
-        let value = get_latitude_text().parse::<f32>().ok()?;
-        Latitude::try_from(value).ok()
+            let coordinates = coordinates?.into_inner();
+            Some(air_quality_source.current_air_quality(coordinates).await)
+        }
     };
 
-    let get_longitude = move || {
-        let value = get_longitude_text().parse::<f32>().ok()?;
-        Longitude::try_from(value).ok()
+    let air_quality_resource = create_local_resource(move || (get_mode(), get_coordinates()), air_quality_fetcher);
+    let get_air_quality = Signal::derive(move || air_quality_resource.get().flatten());
+
+    let marine_fetcher = move |(mode, coordinates): (Mode, Option<NeverEqual<Coordinates>>)| {
+        let marine_source = marine_source.clone();
+
+        async move {
+            if mode != Mode::Forecast {
+                return None;
+            }
+
+            let coordinates = coordinates?.into_inner();
+            Some(marine_source.current_marine_conditions(coordinates).await)
+        }
+    };
+
+    let marine_resource = create_local_resource(move || (get_mode(), get_coordinates()), marine_fetcher);
+    let get_marine_conditions = Signal::derive(move || marine_resource.get().flatten());
+
+    let get_attribution = Signal::derive(move || {
+        match get_mode() {
+            Mode::Forecast | Mode::Dashboard => (get_provider().name(), get_provider().attribution_url()),
+            Mode::History => (weather_archive.name(), weather_archive.attribution_url()),
+        }
+    });
+
+    let dashboard_geolocation_source = geolocation_source.clone();
+
+    let main = move || {
+        if get_mode() == Mode::Dashboard {
+            return view! {
+                <Dashboard
+                    geolocation_source=dashboard_geolocation_source.clone()
+                    weather_cache=weather_cache.clone()
+                    provider=get_provider
+                    revalidated=revalidated
+                    get_saved_locations=get_saved_locations
+                    set_saved_locations=set_saved_locations
+                    set_coordinates=set_coordinates
+                    forecast_days=get_forecast_days
+                    unit_system=get_unit_system
+                    locale=get_locale
+                />
+            };
+        }
+
+        view! {
+            <Main
+                mode=get_mode
+                coordinates=Signal::derive(move || get_coordinates().map(NeverEqual::into_inner))
+                weather_data_state=weather_data_state
+                history_state=history_state
+                air_quality=get_air_quality
+                marine_conditions=get_marine_conditions
+                geolocation_error=get_geolocation_error
+                is_online=get_is_online
+                unit_system=get_unit_system
+                locale=get_locale
+            />
+        }
     };
 
-    let get_floating_coordinates = move || {
-        let latitude = get_latitude()?;
-        let longitude = get_longitude()?;
-        Some(Coordinates { latitude, longitude })
+    view! {
+        <Header
+            geocoder=geocoder
+            geolocation_source=geolocation_source
+            providers=providers.clone()
+            get_provider_index=get_provider_index
+            set_provider_index=set_provider_index
+            set_coordinates=set_coordinates
+            set_geolocation_error=set_geolocation_error
+            get_forecast_days=get_forecast_days
+            set_forecast_days=set_forecast_days
+            max_forecast_days=get_max_forecast_days
+            get_unit_system=get_unit_system
+            set_unit_system=set_unit_system
+            get_mode=get_mode
+            set_mode=set_mode
+            set_history_range=set_history_range
+            get_coordinates=get_coordinates
+            set_saved_locations=set_saved_locations
+            locale=get_locale
+            get_locale_override=get_locale_override
+            set_locale_override=set_locale_override
+        />
+        { main }
+        <Footer attribution=get_attribution locale=get_locale/>
+    }
+}
+
+#[component]
+fn Header(geocoder: Rc<dyn Geocoder>,
+          geolocation_source: Rc<dyn GeolocationSource>,
+          providers: Rc<[Rc<dyn WeatherProvider>]>,
+          get_provider_index: ReadSignal<usize>,
+          set_provider_index: WriteSignal<usize>,
+          set_coordinates: WriteSignal<Option<NeverEqual<Coordinates>>>,
+          set_geolocation_error: WriteSignal<Option<geolocation::Error>>,
+          get_forecast_days: ReadSignal<ForecastDays>,
+          set_forecast_days: WriteSignal<ForecastDays>,
+          max_forecast_days: Signal<ForecastDays>,
+          get_unit_system: ReadSignal<UnitSystem>,
+          set_unit_system: WriteSignal<UnitSystem>,
+          get_mode: ReadSignal<Mode>,
+          set_mode: WriteSignal<Mode>,
+          set_history_range: WriteSignal<Option<HistoryRange>>,
+          get_coordinates: ReadSignal<Option<NeverEqual<Coordinates>>>,
+          set_saved_locations: WriteSignal<SavedLocations>,
+          locale: Signal<Locale>,
+          get_locale_override: ReadSignal<Option<Locale>>,
+          set_locale_override: WriteSignal<Option<Locale>>) -> impl IntoView {
+    let (get_coordinates_text, set_coordinates_text) = create_signal(String::default());
+
+    let get_parsed_coordinates = move || {
+        let text = get_coordinates_text();
+        (!text.trim().is_empty()).then(|| text.parse::<Coordinates>())
     };
 
+    // Keeps the input in line with coordinates chosen elsewhere, e.g. from the URL or a place search
+    create_effect(move |_| {
+        if let Some(coordinates) = get_coordinates().map(NeverEqual::into_inner) {
+            set_coordinates_text(coordinates.to_string());
+        }
+    });
+
     view! {
         <header>
             <h1>Stormy Skies</h1>
 
+            <PlaceSearch geocoder=geocoder set_coordinates=set_coordinates unit_system=get_unit_system locale=locale/>
+
             <input
-                placeholder = "Latitude"
-                on:input = move |event| {
-                    let value = event_target_value(&event);
-                    set_latitude_text(value);
+                placeholder = text(locale, Text::Coordinates)
+                prop:value = get_coordinates_text
+                on:input = move |event| set_coordinates_text(event_target_value(&event))
+            />
+
+            <button
+                on:click = move |_| { // TODO add a cool-down for this button to prevent spamming
+                    set_coordinates(get_parsed_coordinates().and_then(Result::ok).map(NeverEqual));
                 }
+            >{ text(locale, Text::Forecast) }</button>
+
+            <span class="input-error">{ move || get_parsed_coordinates().and_then(Result::err).map(|error| error.to_string()) }</span>
+
+            <LocationCodes coordinates=Signal::derive(move || get_coordinates().map(NeverEqual::into_inner))/>
+
+            <UseMyLocationButton
+                geolocation_source=geolocation_source
+                set_coordinates=set_coordinates
+                set_geolocation_error=set_geolocation_error
+                locale=locale
             />
 
+            <ModeSelector get_mode=get_mode set_mode=set_mode locale=locale/>
+
+            { move || match get_mode() {
+                Mode::Forecast => view! {
+                    <ProviderSelector providers=providers.clone() get_provider_index=get_provider_index set_provider_index=set_provider_index/>
+                    <ForecastDaysSelector
+                        get_forecast_days=get_forecast_days
+                        set_forecast_days=set_forecast_days
+                        max_forecast_days=max_forecast_days
+                        locale=locale
+                    />
+                    <SaveLocationForm get_coordinates=get_coordinates set_saved_locations=set_saved_locations locale=locale/>
+                }.into_view(),
+
+                Mode::History => view! {
+                    <DateRangePicker set_history_range=set_history_range/>
+                }.into_view(),
+
+                Mode::Dashboard => view! {
+                    <ProviderSelector providers=providers.clone() get_provider_index=get_provider_index set_provider_index=set_provider_index/>
+                }.into_view(),
+            } }
+
+            <UnitSystemSelector get_unit_system=get_unit_system set_unit_system=set_unit_system locale=locale/>
+            <LocaleSelector get_locale_override=get_locale_override set_locale_override=set_locale_override locale=locale/>
+        </header>
+    }
+}
+
+const GEOHASH_LENGTH: usize = 7;
+const MAIDENHEAD_PAIRS: usize = 3;
+const PLUS_CODE_LENGTH: usize = 10;
+
+/// The chosen coordinates in other notations, each of which is also accepted as input.
+#[component]
+fn LocationCodes(coordinates: Signal<Option<Coordinates>>) -> impl IntoView {
+    move || coordinates().map(|coordinates| view! {
+        <p class="location-codes">
+            "Plus Code " { coordinates.to_plus_code(PLUS_CODE_LENGTH) }
+            " · Maidenhead " { coordinates.to_maidenhead(MAIDENHEAD_PAIRS) }
+            " · Geohash " { coordinates.to_geohash(GEOHASH_LENGTH) }
+        </p>
+    })
+}
+
+const PLACE_SEARCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+#[component]
+fn PlaceSearch(geocoder: Rc<dyn Geocoder>,
+               set_coordinates: WriteSignal<Option<NeverEqual<Coordinates>>>,
+               unit_system: ReadSignal<UnitSystem>,
+               locale: Signal<Locale>) -> impl IntoView {
+    let (get_search_text, set_search_text) = create_signal(String::default());
+    let (get_query, set_query) = create_signal(String::default());
+
+    let source = move || (AlwaysEqual(geocoder.clone()), get_query());
+
+    async fn fetcher((geocoder, query): (AlwaysEqual<Rc<dyn Geocoder>>, String)) -> Result<Vec<Place>, geocoding::Error> {
+        if query.trim().chars().count() < 2 {
+            return Ok(Vec::new());
+        }
+
+        let geocoder = geocoder.into_inner();
+        geocoder.search(query.trim()).await
+    }
+
+    let places_resource = create_local_resource(source, fetcher);
+    let places = move || places_resource.get().and_then(Result::ok).unwrap_or_default();
+    let search_error = move || places_resource.get().and_then(Result::err).map(|error| error.to_string());
+
+    let select_place = move |place: Place| {
+        set_search_text(place.to_string());
+        set_query(String::default());
+        set_coordinates(Some(NeverEqual(place.coordinates)));
+    };
+
+    view! {
+        <div id="place-search">
             <input
-                placeholder = "Longitude"
+                placeholder = text(locale, Text::SearchPlace)
+                prop:value = get_search_text
                 on:input = move |event| {
-                    let value = event_target_value(&event);
-                    set_longitude_text(value);
+                    let text = event_target_value(&event);
+                    set_search_text(text.clone());
+
+                    set_timeout(move || {
+                        if get_search_text.get_untracked() == text {
+                            set_query(text);
+                        }
+                    }, PLACE_SEARCH_DEBOUNCE);
                 }
             />
 
-            <button
-                on:click = move |_| { // TODO add a cool-down for this button to prevent spamming
-                    set_coordinates(get_floating_coordinates().map(NeverEqual));
+            <span class="input-error">{ search_error }</span>
+
+            <ul id="place-search-suggestions">
+                <For
+                    each = places
+                    key = |place| place.to_string()
+                    children = move |place| {
+                        let label = {
+                            let place = place.clone();
+
+                            move || match place.elevation {
+                                Some(elevation) => format!("{} ({})", place, format_measurement(elevation, unit_system())),
+                                None => place.to_string(),
+                            }
+                        };
+
+                        view! {
+                            <li on:click = move |_| select_place(place.clone())>{ label }</li>
+                        }
+                    }
+                />
+            </ul>
+        </div>
+    }
+}
+
+#[component]
+fn UseMyLocationButton(geolocation_source: Rc<dyn GeolocationSource>,
+                       set_coordinates: WriteSignal<Option<NeverEqual<Coordinates>>>,
+                       set_geolocation_error: WriteSignal<Option<geolocation::Error>>,
+                       locale: Signal<Locale>) -> impl IntoView {
+    let (get_locating, set_locating) = create_signal(false);
+
+    let locate = move |_| {
+        let geolocation_source = geolocation_source.clone();
+        set_locating(true);
+
+        spawn_local(async move {
+            match geolocation_source.current_position().await {
+                Ok(coordinates) => set_coordinates(Some(NeverEqual(coordinates))),
+                Err(error) => set_geolocation_error(Some(error)),
+            }
+
+            set_locating(false);
+        });
+    };
+
+    view! {
+        <button on:click=locate disabled=get_locating>{ text(locale, Text::UseMyLocation) }</button>
+    }
+}
+
+#[component]
+fn ProviderSelector(providers: Rc<[Rc<dyn WeatherProvider>]>,
+                    get_provider_index: ReadSignal<usize>,
+                    set_provider_index: WriteSignal<usize>) -> impl IntoView {
+    let provider_count = providers.len();
+
+    let options = providers.iter().enumerate().map(|(index, provider)| {
+        let selected = move || get_provider_index() == index;
+        view! { <option value={ index } selected=selected>{ provider.name() }</option> }
+    }).collect_view();
+
+    view! {
+        <select
+            on:change = move |event| {
+                if let Ok(index) = event_target_value(&event).parse::<usize>() {
+                    if index < provider_count {
+                        set_provider_index(index);
+                    }
                 }
-            >Forecast</button>
-        </header>
+            }
+        >{ options }</select>
+    }
+}
+
+#[component]
+fn ForecastDaysSelector(get_forecast_days: ReadSignal<ForecastDays>,
+                        set_forecast_days: WriteSignal<ForecastDays>,
+                        max_forecast_days: Signal<ForecastDays>,
+                        locale: Signal<Locale>) -> impl IntoView {
+    let options = move || (MIN_FORECAST_DAYS..=u8::from(max_forecast_days())).map(|days| {
+        let selected = move || u8::from(get_forecast_days()) == days;
+        view! { <option value={ days } selected=selected>{ days } " " { text(locale, Text::Days) }</option> }
+    }).collect_view();
+
+    view! {
+        <select
+            on:change = move |event| {
+                if let Ok(Ok(forecast_days)) = event_target_value(&event).parse::<u8>().map(ForecastDays::try_from) {
+                    set_forecast_days(forecast_days);
+                }
+            }
+        >{ options }</select>
+    }
+}
+
+#[component]
+fn ModeSelector(get_mode: ReadSignal<Mode>, set_mode: WriteSignal<Mode>, locale: Signal<Locale>) -> impl IntoView {
+    view! {
+        <select
+            on:change = move |event| {
+                match event_target_value(&event).as_str() {
+                    "history" => set_mode(Mode::History),
+                    "dashboard" => set_mode(Mode::Dashboard),
+                    _ => set_mode(Mode::Forecast),
+                }
+            }
+        >
+            <option value="forecast" selected={ move || get_mode() == Mode::Forecast }>{ text(locale, Text::Forecast) }</option>
+            <option value="history" selected={ move || get_mode() == Mode::History }>{ text(locale, Text::History) }</option>
+            <option value="dashboard" selected={ move || get_mode() == Mode::Dashboard }>{ text(locale, Text::Dashboard) }</option>
+        </select>
+    }
+}
+
+#[component]
+fn SaveLocationForm(get_coordinates: ReadSignal<Option<NeverEqual<Coordinates>>>,
+                    set_saved_locations: WriteSignal<SavedLocations>,
+                    locale: Signal<Locale>) -> impl IntoView {
+    let (get_name, set_name) = create_signal(String::default());
+
+    let save = move |_| {
+        let Some(coordinates) = get_coordinates.get_untracked().map(NeverEqual::into_inner) else {
+            return;
+        };
+
+        let name = match get_name.get_untracked().trim() {
+            "" => coordinates.to_string(),
+            name => name.to_owned(),
+        };
+
+        set_saved_locations.update(|saved_locations| { saved_locations.add(name, coordinates); });
+        set_name(String::default());
+    };
+
+    view! {
+        <input
+            placeholder = text(locale, Text::Name)
+            prop:value = get_name
+            on:input = move |event| set_name(event_target_value(&event))
+        />
+        <button on:click=save disabled = move || get_coordinates().is_none()>{ text(locale, Text::SaveLocation) }</button>
+    }
+}
+
+fn parse_history_range(start: &str, end: &str) -> Result<HistoryRange, String> {
+    let parse_date = |text: &str| text.parse::<Date>().map_err(|error| error.to_string());
+    let date_range = DateRange::new(parse_date(start)?, parse_date(end)?).map_err(|error| error.to_string())?;
+    HistoryRange::try_from(date_range).map_err(|error| error.to_string())
+}
+
+#[component]
+fn DateRangePicker(set_history_range: WriteSignal<Option<HistoryRange>>) -> impl IntoView {
+    let (get_start_text, set_start_text) = create_signal(String::default());
+    let (get_end_text, set_end_text) = create_signal(String::default());
+
+    let history_range = move || {
+        let (start, end) = (get_start_text(), get_end_text());
+
+        if start.is_empty() || end.is_empty() {
+            return None;
+        }
+
+        Some(parse_history_range(&start, &end))
+    };
+
+    create_effect(move |_| set_history_range(history_range().and_then(Result::ok)));
+
+    view! {
+        <input type="date" on:input = move |event| set_start_text(event_target_value(&event))/>
+        { " – " }
+        <input type="date" on:input = move |event| set_end_text(event_target_value(&event))/>
+        <span class="input-error">{ move || history_range().and_then(Result::err) }</span>
+    }
+}
+
+#[component]
+fn UnitSystemSelector(get_unit_system: ReadSignal<UnitSystem>,
+                      set_unit_system: WriteSignal<UnitSystem>,
+                      locale: Signal<Locale>) -> impl IntoView {
+    let (get_custom, set_custom) = create_signal(false);
+
+    let preset = move || {
+        match get_unit_system() {
+            _ if get_custom() => "custom",
+            UnitSystem::METRIC => "metric",
+            UnitSystem::IMPERIAL => "imperial",
+            _ => "custom",
+        }
+    };
+
+    view! {
+        <select
+            on:change = move |event| {
+                match event_target_value(&event).as_str() {
+                    "metric" => { set_custom(false); set_unit_system(UnitSystem::METRIC); },
+                    "imperial" => { set_custom(false); set_unit_system(UnitSystem::IMPERIAL); },
+                    _ => set_custom(true),
+                }
+            }
+        >
+            <option value="metric" selected={ move || preset() == "metric" }>{ text(locale, Text::Metric) }</option>
+            <option value="imperial" selected={ move || preset() == "imperial" }>{ text(locale, Text::Imperial) }</option>
+            <option value="custom" selected={ move || preset() == "custom" }>{ text(locale, Text::Custom) }</option>
+        </select>
+
+        <Show when = move || preset() == "custom">
+            <UnitSelector
+                units = &TemperatureUnit::ALL
+                symbol = TemperatureUnit::symbol
+                get_unit = move || get_unit_system().temperature
+                set_unit = move |temperature| set_unit_system.update(|unit_system| unit_system.temperature = temperature)
+            />
+            <UnitSelector
+                units = &SpeedUnit::ALL
+                symbol = SpeedUnit::symbol
+                get_unit = move || get_unit_system().speed
+                set_unit = move |speed| set_unit_system.update(|unit_system| unit_system.speed = speed)
+            />
+            <UnitSelector
+                units = &PressureUnit::ALL
+                symbol = PressureUnit::symbol
+                get_unit = move || get_unit_system().pressure
+                set_unit = move |pressure| set_unit_system.update(|unit_system| unit_system.pressure = pressure)
+            />
+            <UnitSelector
+                units = &PrecipitationUnit::ALL
+                symbol = PrecipitationUnit::symbol
+                get_unit = move || get_unit_system().precipitation
+                set_unit = move |precipitation| set_unit_system.update(|unit_system| unit_system.precipitation = precipitation)
+            />
+            <UnitSelector
+                units = &LengthUnit::ALL
+                symbol = LengthUnit::symbol
+                get_unit = move || get_unit_system().length
+                set_unit = move |length| set_unit_system.update(|unit_system| unit_system.length = length)
+            />
+            <UnitSelector
+                units = &LengthUnit::ALL
+                symbol = LengthUnit::symbol
+                get_unit = move || get_unit_system().distance
+                set_unit = move |distance| set_unit_system.update(|unit_system| unit_system.distance = distance)
+            />
+        </Show>
+    }
+}
+
+#[component]
+fn UnitSelector<U, G, S>(units: &'static [U],
+                         symbol: fn(&U) -> &'static str,
+                         get_unit: G,
+                         set_unit: S) -> impl IntoView
+    where U: Copy + PartialEq + 'static,
+          G: Fn() -> U + Copy + 'static,
+          S: Fn(U) + 'static {
+
+    let options = units.iter().enumerate().map(|(index, unit)| {
+        let selected = move || get_unit() == *unit;
+        view! { <option value={ index } selected=selected>{ symbol(unit) }</option> }
+    }).collect_view();
+
+    view! {
+        <select
+            on:change = move |event| {
+                if let Some(unit) = event_target_value(&event).parse::<usize>().ok().and_then(|index| units.get(index)) {
+                    set_unit(*unit);
+                }
+            }
+        >{ options }</select>
     }
 }
 
+/// Follows the language of the browser unless a locale is chosen explicitly.
 #[component]
-fn Main<F>(weather_data_state: F) -> impl IntoView where F: Fn() -> ApiCallState + 'static {
+fn LocaleSelector(get_locale_override: ReadSignal<Option<Locale>>,
+                  set_locale_override: WriteSignal<Option<Locale>>,
+                  locale: Signal<Locale>) -> impl IntoView {
+    let options = Locale::ALL.into_iter().map(|option| {
+        let selected = move || get_locale_override() == Some(option);
+        view! { <option value={ option.code() } selected=selected>{ option.name() }</option> }
+    }).collect_view();
+
+    view! {
+        <select on:change = move |event| set_locale_override(Locale::from_language_tag(&event_target_value(&event)))>
+            <option value="" selected={ move || get_locale_override().is_none() }>{ text(locale, Text::BrowserLanguage) }</option>
+            { options }
+        </select>
+    }
+}
+
+/// Reactive translation of a fixed text of the user interface.
+fn text(locale: Signal<Locale>, text: Text) -> impl Fn() -> &'static str + Copy + 'static {
+    move || text.translate(locale())
+}
+
+fn format_measurement<M: Measurement>(measurement: M, unit_system: UnitSystem) -> String {
+    let measurement = measurement.to_unit_system(unit_system);
+    format!("{:.*}", measurement.decimal_places(), measurement)
+}
+
+/// Views the state of an API call, leaving only the view of the response to the caller.
+fn view_api_call_state<T>(state: ApiCallState<T>, locale: Signal<Locale>, view_response: impl FnOnce(T) -> View) -> View {
+    match state {
+        ApiCallState::NotCalled =>
+            view! { <MainBeforeFirstRequest locale=locale/> },
+
+        ApiCallState::ResponsePending =>
+            view! { <MainWhileRequestPending locale=locale/> },
+
+        ApiCallState::Error(error) if error.is_offline() =>
+            view! { <MainWhileOffline locale=locale/> },
+
+        ApiCallState::Error(error) =>
+            view! { <MainWithError error={ move || error.clone() } locale=locale/> },
+
+        ApiCallState::Responded(response) =>
+            view_response(response),
+    }
+}
+
+#[component]
+fn Main<F, H>(mode: ReadSignal<Mode>,
+              coordinates: Signal<Option<Coordinates>>,
+              weather_data_state: F,
+              history_state: H,
+              air_quality: Signal<Option<Result<AirQuality, air_quality::Error>>>,
+              marine_conditions: Signal<Option<Result<Option<MarineConditions>, marine::Error>>>,
+              geolocation_error: ReadSignal<Option<geolocation::Error>>,
+              is_online: ReadSignal<bool>,
+              unit_system: ReadSignal<UnitSystem>,
+              locale: Signal<Locale>) -> impl IntoView
+    where F: Fn() -> ApiCallState<CachedWeatherData> + 'static,
+          H: Fn() -> ApiCallState<HistoricalWeatherData> + 'static {
+
     let load_main = move || {
-        match weather_data_state() {
-            ApiCallState::NotCalled =>
-                MainBeforeFirstRequest().into_view(),
+        if let Some(error) = geolocation_error() {
+            return view! { <MainWithError error={ move || error.clone() } locale=locale/> };
+        }
 
-            ApiCallState::ResponsePending =>
-                MainWhileRequestPending().into_view(),
+        match mode() {
+            Mode::Forecast => view_api_call_state(weather_data_state(), locale, |cached| {
+                let is_stale = cached.is_stale;
+                let refresh_failed = cached.refresh_failed;
+                let weather_data = Signal::derive(move || cached.weather_data.clone());
+                view! {
+                    <MainWithLoadedData
+                        weather_data=weather_data
+                        coordinates=coordinates
+                        air_quality=air_quality
+                        marine_conditions=marine_conditions
+                        is_stale=is_stale
+                        refresh_failed=refresh_failed
+                        is_online=is_online
+                        unit_system=unit_system
+                        locale=locale
+                    />
+                }
+            }),
 
-            ApiCallState::Error(error) =>
-                view! { <MainWithError error={ move || error.clone() }/> },
+            Mode::History => view_api_call_state(history_state(), locale, |history| {
+                let history = Signal::derive(move || history.clone());
+                view! { <MainWithHistoricalData history=history unit_system=unit_system locale=locale /> }
+            }),
 
-            ApiCallState::Responded(weather_data) => {
-                let weather_data = Signal::derive(move || weather_data.clone());
-                view! { <MainWithLoadedData weather_data=weather_data /> }
-            },
+            // The dashboard replaces this view entirely
+            Mode::Dashboard => ().into_view(),
         }
     };
 
@@ -127,46 +849,272 @@ fn Main<F>(weather_data_state: F) -> impl IntoView where F: Fn() -> ApiCallState
     }
 }
 
+/// Compact current weather of all saved locations, each loaded on its own so that they are fetched concurrently.
+#[component]
+fn Dashboard(geolocation_source: Rc<dyn GeolocationSource>,
+             weather_cache: Rc<WeatherCache>,
+             provider: Signal<Rc<dyn WeatherProvider>>,
+             revalidated: Trigger,
+             get_saved_locations: ReadSignal<SavedLocations>,
+             set_saved_locations: WriteSignal<SavedLocations>,
+             set_coordinates: WriteSignal<Option<NeverEqual<Coordinates>>>,
+             forecast_days: ReadSignal<ForecastDays>,
+             unit_system: ReadSignal<UnitSystem>,
+             locale: Signal<Locale>) -> impl IntoView {
+    let locations = move || get_saved_locations.with(|saved_locations| saved_locations.locations().to_vec());
+
+    view! {
+        <main>
+            <Show when = move || get_saved_locations.with(SavedLocations::is_empty)>
+                <h1>{ text(locale, Text::NoSavedLocations) }</h1>
+                <p>{ text(locale, Text::SavedLocationsHint) }</p>
+            </Show>
+
+            <Show when = move || get_saved_locations.with(|saved_locations| saved_locations.locations().len() > 1)>
+                <SortByDistanceButton
+                    geolocation_source=geolocation_source.clone()
+                    set_saved_locations=set_saved_locations
+                    locale=locale
+                />
+            </Show>
+
+            <div id="dashboard">
+                <For
+                    each = locations
+                    key = |location| location.id
+                    children = move |location| view! {
+                        <DashboardCard
+                            location=location
+                            weather_cache=weather_cache.clone()
+                            provider=provider
+                            revalidated=revalidated
+                            set_saved_locations=set_saved_locations
+                            set_coordinates=set_coordinates
+                            forecast_days=forecast_days
+                            unit_system=unit_system
+                            locale=locale
+                        />
+                    }
+                />
+            </div>
+        </main>
+    }
+}
+
+/// Orders the saved locations by their distance from the current position of the user.
+#[component]
+fn SortByDistanceButton(geolocation_source: Rc<dyn GeolocationSource>,
+                        set_saved_locations: WriteSignal<SavedLocations>,
+                        locale: Signal<Locale>) -> impl IntoView {
+    let (get_locating, set_locating) = create_signal(false);
+    let (get_error, set_error) = create_signal(None::<geolocation::Error>);
+
+    let sort = move |_| {
+        let geolocation_source = geolocation_source.clone();
+        set_locating(true);
+        set_error(None);
+
+        spawn_local(async move {
+            match geolocation_source.current_position().await {
+                Ok(coordinates) => set_saved_locations.update(|saved_locations| saved_locations.sort_by_distance(coordinates)),
+                Err(error) => set_error(Some(error)),
+            }
+
+            set_locating(false);
+        });
+    };
+
+    view! {
+        <div class="sort-by-distance">
+            <button on:click=sort disabled=get_locating>{ text(locale, Text::SortByDistance) }</button>
+            { move || get_error().map(|error| view! { <span class="input-error">{ error.to_string() }</span> }) }
+        </div>
+    }
+}
+
+#[component]
+fn DashboardCard(location: SavedLocation,
+                 weather_cache: Rc<WeatherCache>,
+                 provider: Signal<Rc<dyn WeatherProvider>>,
+                 revalidated: Trigger,
+                 set_saved_locations: WriteSignal<SavedLocations>,
+                 set_coordinates: WriteSignal<Option<NeverEqual<Coordinates>>>,
+                 forecast_days: ReadSignal<ForecastDays>,
+                 unit_system: ReadSignal<UnitSystem>,
+                 locale: Signal<Locale>) -> impl IntoView {
+    let SavedLocation { id, name, coordinates } = location;
+
+    let source = move || {
+        revalidated.track();
+        (AlwaysEqual(provider()), provider().name(), forecast_days())
+    };
+
+    let fetcher = move |(provider, _, forecast_days): (AlwaysEqual<Rc<dyn WeatherProvider>>, &'static str, ForecastDays)| {
+        let weather_cache = weather_cache.clone();
+
+        async move {
+            let provider = provider.into_inner();
+            let request = WeatherRequest { weather_cache, provider, coordinates, forecast_days };
+            request.fetch_cached(revalidated).await
+        }
+    };
+
+    let weather_data_resource = create_local_resource(source, fetcher);
+
+    let weather = move || {
+        match weather_data_resource.get() {
+            None => view! { <p>{ text(locale, Text::Pending) }</p> }.into_view(),
+            Some(Err(error)) => view! { <p>{ text(locale, Text::Unavailable) } ": " { error.to_string() }</p> }.into_view(),
+            Some(Ok(cached)) => {
+                let weather_data = cached.weather_data;
+                let temperature = move || format_measurement(weather_data.current_temperature, unit_system());
+                let weather = weather_data.current_weather;
+                let icon_path = weather.icon_path.clone();
+                let description = Signal::derive(move || weather.description(locale()));
+
+                view! {
+                    <img src=icon_path title=description/>
+                    <p class="dashboard-card-temperature">{ temperature }</p>
+                    <p>{ description }</p>
+                }.into_view()
+            },
+        }
+    };
+
+    view! {
+        <div class="card dashboard-card">
+            <input
+                class = "dashboard-card-name"
+                value = name
+                on:change = move |event| {
+                    let name = event_target_value(&event);
+                    set_saved_locations.update(|saved_locations| saved_locations.rename(id, name));
+                }
+            />
+
+            <div class="dashboard-card-weather" on:click = move |_| set_coordinates(Some(NeverEqual(coordinates)))>
+                { weather }
+            </div>
+
+            <div class="dashboard-card-controls">
+                <button title=text(locale, Text::MoveUp) on:click = move |_| set_saved_locations.update(|saved_locations| saved_locations.move_up(id))>"↑"</button>
+                <button title=text(locale, Text::MoveDown) on:click = move |_| set_saved_locations.update(|saved_locations| saved_locations.move_down(id))>"↓"</button>
+                <button on:click = move |_| set_saved_locations.update(|saved_locations| saved_locations.remove(id))>{ text(locale, Text::Remove) }</button>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn MainBeforeFirstRequest(locale: Signal<Locale>) -> impl IntoView {
+    view! {
+        <h1>{ text(locale, Text::NoRequestMade) }</h1>
+    }
+}
+
 #[component]
-fn MainBeforeFirstRequest() -> impl IntoView {
+fn MainWhileRequestPending(locale: Signal<Locale>) -> impl IntoView {
     view! {
-        <h1>No Request made</h1>
+        <h1>{ text(locale, Text::Pending) }</h1>
     }
 }
 
 #[component]
-fn MainWhileRequestPending() -> impl IntoView {
+fn MainWhileOffline(locale: Signal<Locale>) -> impl IntoView {
     view! {
-        <h1>Pending...</h1>
+        <h1>{ text(locale, Text::Offline) }</h1>
+        <p>{ text(locale, Text::OfflineHint) }</p>
     }
 }
 
 #[component]
-fn MainWithError<F>(error: F) -> impl IntoView where F: Fn() -> open_meteo::Error + 'static {
+fn MainWithError<F, E>(error: F, locale: Signal<Locale>) -> impl IntoView where F: Fn() -> E + 'static, E: std::error::Error {
     view! {
-        <h1>Error: { move || error().to_string() }</h1>
+        <h1>{ text(locale, Text::Error) } ": " { move || error().to_string() }</h1>
     }
 }
 
 #[component]
-fn MainWithLoadedData(weather_data: Signal<WeatherData>) -> impl IntoView {
+fn MainWithLoadedData(weather_data: Signal<WeatherData>,
+                      coordinates: Signal<Option<Coordinates>>,
+                      air_quality: Signal<Option<Result<AirQuality, air_quality::Error>>>,
+                      marine_conditions: Signal<Option<Result<Option<MarineConditions>, marine::Error>>>,
+                      is_stale: bool,
+                      refresh_failed: bool,
+                      is_online: ReadSignal<bool>,
+                      unit_system: ReadSignal<UnitSystem>,
+                      locale: Signal<Locale>) -> impl IntoView {
+    let stale_notice = move || {
+        if !is_online() {
+            Text::StaleDataWhileOffline.translate(locale())
+        } else if refresh_failed {
+            Text::StaleDataRefreshFailed.translate(locale())
+        } else {
+            Text::StaleData.translate(locale())
+        }
+    };
+
     view! {
+        <Show when = move || is_stale>
+            <p class="stale-notice">{ stale_notice }</p>
+        </Show>
+
         <div id="main-with-loaded-data">
-            <CurrentWeatherSummaryCard weather_data=weather_data />
+            <CurrentWeatherSummaryCard weather_data=weather_data coordinates=coordinates unit_system=unit_system locale=locale />
+            <AirQualityCard air_quality=air_quality locale=locale />
+            <MarineCard marine_conditions=marine_conditions unit_system=unit_system locale=locale />
+            <DaylightCard coordinates=coordinates utc_offset_seconds={ Signal::derive(move || weather_data().utc_offset_seconds) } locale=locale />
+            <DailyForecastList daily_forecast={ move || weather_data().daily_forecast } unit_system=unit_system locale=locale />
+            <HourlyForecastStrip hourly_forecast={ move || weather_data().hourly_forecast } unit_system=unit_system locale=locale />
         </div>
     }
 }
 
 #[component]
-fn CurrentWeatherSummaryCard(weather_data: Signal<WeatherData>) -> impl IntoView {
+fn MainWithHistoricalData(history: Signal<HistoricalWeatherData>, unit_system: ReadSignal<UnitSystem>, locale: Signal<Locale>) -> impl IntoView {
+    view! {
+        <div id="main-with-loaded-data">
+            <DailyForecastList daily_forecast={ move || history().daily } unit_system=unit_system locale=locale />
+            <HourlyForecastStrip hourly_forecast={ move || history().hourly } unit_system=unit_system locale=locale />
+        </div>
+    }
+}
+
+#[component]
+fn CurrentWeatherSummaryCard(weather_data: Signal<WeatherData>,
+                             coordinates: Signal<Option<Coordinates>>,
+                             unit_system: ReadSignal<UnitSystem>,
+                             locale: Signal<Locale>) -> impl IntoView {
     let icon_path = move || weather_data().current_weather.icon_path;
-    let weather_description = move || weather_data().current_weather.description;
-    let temperature = move || weather_data().current_temperature.to_string();
-    let wind_speed = move || weather_data().current_wind_speed.to_string();
-    let wind_direction = move || weather_data().current_wind_direction.to_string();
-    let pressure = move || weather_data().current_pressure.to_string();
+    let weather_description = move || weather_data().current_weather.description(locale());
+    let temperature = move || format_measurement(weather_data().current_temperature, unit_system());
+    let wind_speed = move || format_measurement(weather_data().current_wind_speed, unit_system());
+    let pressure = move || format_measurement(weather_data().current_pressure, unit_system());
     let humidity = move || weather_data().current_humidity.to_string();
 
+    let feels_like = move || {
+        let weather_data = weather_data();
+        let apparent_temperature = derived::apparent_temperature(weather_data.current_temperature, weather_data.current_humidity, weather_data.current_wind_speed);
+        format_measurement(apparent_temperature, unit_system())
+    };
+
+    let dew_point = move || {
+        let weather_data = weather_data();
+
+        derived::dew_point(weather_data.current_temperature, weather_data.current_humidity)
+            .map(|dew_point| format_measurement(dew_point, unit_system()))
+            .unwrap_or_else(|| "–".to_owned())
+    };
+
+    // Offset of the model grid point from the requested position, e.g. "2.3 km NE"
+    let grid_point = move || {
+        let requested = coordinates()?;
+        let grid = weather_data().grid_coordinates?;
+        // Kilometres mark the length as a distance, which is then shown in the distance unit of the system
+        let distance = format_measurement(requested.haversine_distance(&grid).to_kilometers(), unit_system());
+        Some(format!("{} {}", distance, requested.initial_compass_direction(&grid).abbreviation(locale())))
+    };
+
     view! {
         <div class="card" id="current-weather-summary">
             <img id="current-weather-icon" src={ icon_path }/>
@@ -174,18 +1122,295 @@ fn CurrentWeatherSummaryCard(weather_data: Signal<WeatherData>) -> impl IntoView
             <h1 id="current-temperature">{ temperature }</h1>
 
             <div id="current-weather-details">
-                <p>Wind Speed</p> <p>{ wind_speed }</p>
-                <p>Wind Direction</p> <p>{ wind_direction }</p>
-                <p>Pressure</p> <p>{ pressure }</p>
-                <p>Humidity</p> <p>{ humidity }</p>
+                <p>{ text(locale, Text::FeelsLike) }</p> <p>{ feels_like }</p>
+                <p>{ text(locale, Text::DewPoint) }</p> <p>{ dew_point }</p>
+                <p>{ text(locale, Text::WindSpeed) }</p> <p>{ wind_speed }</p>
+                <p>{ text(locale, Text::WindDirection) }</p> <p><WindDirection bearing=MaybeSignal::derive(move || weather_data().current_wind_direction) locale=locale/></p>
+                <p>{ text(locale, Text::Pressure) }</p> <p>{ pressure }</p>
+                <p>{ text(locale, Text::Humidity) }</p> <p>{ humidity }</p>
+                { move || grid_point().map(|grid_point| view! { <p>{ text(locale, Text::GridPoint) }</p> <p>{ grid_point }</p> }) }
             </div>
         </div>
     }
 }
 
 #[component]
-fn Footer() -> impl IntoView {
+fn AirQualityCard(air_quality: Signal<Option<Result<AirQuality, air_quality::Error>>>, locale: Signal<Locale>) -> impl IntoView {
+    let content = move || {
+        match air_quality() {
+            None => view! { <p>{ text(locale, Text::Pending) }</p> }.into_view(),
+            Some(Err(error)) => view! { <p>{ text(locale, Text::Unavailable) } ": " { error.to_string() }</p> }.into_view(),
+            Some(Ok(air_quality)) => view! { <AirQualityDetails air_quality=air_quality locale=locale /> }.into_view(),
+        }
+    };
+
+    view! {
+        <div class="card" id="air-quality">
+            <h2>{ text(locale, Text::AirQuality) }</h2>
+            { content }
+        </div>
+    }
+}
+
+#[component]
+fn AirQualityDetails(air_quality: AirQuality, locale: Signal<Locale>) -> impl IntoView {
+    // None of these quantities have alternative units, so the unit system does not matter
+    let index = |name: Text, index: Option<AirQualityIndex>| {
+        index.map(|index| {
+            let health_band = index.health_band();
+            let class = format!("health-band-{}", health_band.severity());
+
+            view! {
+                <p>{ text(locale, name) }</p>
+                <p class=class>{ format_measurement(index, UnitSystem::METRIC) } " (" { move || health_band.name(locale()) } ")"</p>
+            }
+        })
+    };
+
+    // Chemical formulas are the same in all languages
+    let concentration = |name: MaybeSignal<&'static str>, concentration: Option<Concentration>| {
+        concentration.map(|concentration| view! {
+            <p>{ move || name.get() }</p>
+            <p>{ format_measurement(concentration, UnitSystem::METRIC) }</p>
+        })
+    };
+
+    let pollen = air_quality.pollen.into_iter().map(|(species, count)| view! {
+        <p>{ move || species.pollen_name(locale()) }</p>
+        <p>{ format_measurement(count, UnitSystem::METRIC) }</p>
+    }).collect_view();
+
+    view! {
+        <div id="air-quality-details">
+            { index(Text::EuropeanAqi, air_quality.european_aqi) }
+            { index(Text::UnitedStatesAqi, air_quality.us_aqi) }
+            { concentration(MaybeSignal::Static("PM2.5"), air_quality.pm2_5) }
+            { concentration(MaybeSignal::Static("PM10"), air_quality.pm10) }
+            { concentration(MaybeSignal::derive(text(locale, Text::Ozone)), air_quality.ozone) }
+            { concentration(MaybeSignal::Static("NO₂"), air_quality.nitrogen_dioxide) }
+            { pollen }
+        </div>
+    }
+}
+
+/// Only shown for coordinates at sea.
+#[component]
+fn MarineCard(marine_conditions: Signal<Option<Result<Option<MarineConditions>, marine::Error>>>,
+              unit_system: ReadSignal<UnitSystem>,
+              locale: Signal<Locale>) -> impl IntoView {
+    let content = move || {
+        match marine_conditions()? {
+            Err(error) => Some(view! { <p>{ text(locale, Text::Unavailable) } ": " { error.to_string() }</p> }.into_view()),
+            Ok(marine_conditions) => {
+                let marine_conditions = marine_conditions?;
+                Some(view! { <MarineDetails marine_conditions=marine_conditions unit_system=unit_system locale=locale /> }.into_view())
+            },
+        }
+    };
+
+    view! {
+        <Show when = move || !matches!(marine_conditions(), None | Some(Ok(None)))>
+            <div class="card" id="marine">
+                <h2>{ text(locale, Text::Marine) }</h2>
+                { content }
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+fn MarineDetails(marine_conditions: MarineConditions, unit_system: ReadSignal<UnitSystem>, locale: Signal<Locale>) -> impl IntoView {
+    let wave_component = |name: Text, component: Option<WaveComponent>| {
+        component.map(|component| view! {
+            <p>{ text(locale, name) }</p>
+            <p>
+                { move || format_measurement(component.height, unit_system()) } " "
+                { move || component.direction.abbreviation(locale()) } ", "
+                { move || format_measurement(component.period, unit_system()) }
+            </p>
+        })
+    };
+
+    let sea_surface_temperature = marine_conditions.sea_surface_temperature.map(|temperature| view! {
+        <p>{ text(locale, Text::SeaSurfaceTemperature) }</p>
+        <p>{ move || format_measurement(temperature, unit_system()) }</p>
+    });
+
+    view! {
+        <div id="marine-details">
+            { wave_component(Text::Waves, Some(marine_conditions.waves)) }
+            { wave_component(Text::WindWaves, marine_conditions.wind_waves) }
+            { wave_component(Text::Swell, marine_conditions.swell) }
+            { sea_surface_temperature }
+        </div>
+    }
+}
+
+/// Calculated locally for the current day, in the time zone of the weather data.
+const DAYLIGHT_UPDATE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+#[component]
+fn DaylightCard(coordinates: Signal<Option<Coordinates>>, utc_offset_seconds: Signal<i32>, locale: Signal<Locale>) -> impl IntoView {
+    let current_minute = || DateTime::from_minutes_since_epoch((js_sys::Date::now() / 60_000.) as i64);
+    let (get_now, set_now) = create_signal(current_minute());
+
+    // The sun keeps moving while the page is open
+    if let Ok(interval_handle) = set_interval_with_handle(move || set_now(current_minute()), DAYLIGHT_UPDATE_INTERVAL) {
+        on_cleanup(move || interval_handle.clear());
+    }
+
+    let content = move || {
+        let coordinates = coordinates()?;
+        let utc_offset_minutes = utc_offset_seconds() / 60;
+
+        let now = get_now();
+        let today = DateTime::from_minutes_since_epoch(now.minutes_since_epoch() + utc_offset_minutes as i64).date();
+
+        let daylight = astronomy::daylight(coordinates, today, utc_offset_minutes);
+        let solar_position = astronomy::solar_position(coordinates, now);
+
+        Some(view! { <DaylightDetails daylight=daylight solar_position=solar_position locale=locale /> })
+    };
+
+    view! {
+        <div class="card" id="daylight">
+            <h2>{ text(locale, Text::Daylight) }</h2>
+            { content }
+        </div>
+    }
+}
+
+#[component]
+fn DaylightDetails(daylight: Daylight, solar_position: SolarPosition, locale: Signal<Locale>) -> impl IntoView {
+    let format_crossing = move |crossing: Crossing, always_above: Text, always_below: Text| {
+        move || match crossing {
+            Crossing::RiseAndSet { rise, set } => format!("{} – {}", rise.time_of_day(), set.time_of_day()),
+            Crossing::AlwaysAbove => always_above.translate(locale()).to_owned(),
+            Crossing::AlwaysBelow => always_below.translate(locale()).to_owned(),
+        }
+    };
+
+    let day_length = daylight.sunrise_and_sunset.minutes_above();
+
+    view! {
+        <div id="daylight-details">
+            <p>{ text(locale, Text::SunriseAndSunset) }</p>
+            <p>{ format_crossing(daylight.sunrise_and_sunset, Text::PolarDay, Text::PolarNight) }</p>
+            <p>{ text(locale, Text::DayLength) }</p> <p>{ format!("{} h {} min", day_length / 60, day_length % 60) }</p>
+            <p>{ text(locale, Text::SolarNoon) }</p> <p>{ daylight.solar_noon.time_of_day() }</p>
+            <p>{ text(locale, Text::CivilTwilight) }</p>
+            <p>{ format_crossing(daylight.civil_twilight, Text::AllDay, Text::NoTwilight) }</p>
+            <p>{ text(locale, Text::NauticalTwilight) }</p>
+            <p>{ format_crossing(daylight.nautical_twilight, Text::AllDay, Text::NoTwilight) }</p>
+            <p>{ text(locale, Text::AstronomicalTwilight) }</p>
+            <p>{ format_crossing(daylight.astronomical_twilight, Text::AllDay, Text::NoTwilight) }</p>
+            <p>{ text(locale, Text::SunElevation) }</p> <p>{ format!("{:.1}°", solar_position.elevation) }</p>
+            <p>{ text(locale, Text::SunAzimuth) }</p> <p>{ format!("{:.0}°", solar_position.azimuth) }</p>
+        </div>
+    }
+}
+
+#[component]
+fn HourlyForecastStrip<F>(hourly_forecast: F, unit_system: ReadSignal<UnitSystem>, locale: Signal<Locale>) -> impl IntoView where F: Fn() -> Vec<HourlyForecast> + 'static {
+    view! {
+        <div class="card" id="hourly-forecast-strip">
+            <For
+                each = hourly_forecast
+                key = |hour| hour.time
+                children = move |hour| view! { <HourlyForecastEntry hour=hour unit_system=unit_system locale=locale /> }
+            />
+        </div>
+    }
+}
+
+#[component]
+fn HourlyForecastEntry(hour: HourlyForecast, unit_system: ReadSignal<UnitSystem>, locale: Signal<Locale>) -> impl IntoView {
+    let temperature = move || format_measurement(hour.temperature, unit_system());
+    let precipitation = move || format_measurement(hour.precipitation, unit_system());
+    let wind_speed = move || format_measurement(hour.wind_speed, unit_system());
+    let weather = hour.weather;
+    let icon_path = weather.icon_path.clone();
+    let description = move || weather.description(locale());
+
+    // Marks where a new day begins, as the strip may span several days
+    let date = (hour.time.hour() == 0).then(|| hour.time.date().to_string());
+
+    view! {
+        <div class="hourly-forecast-entry">
+            <p class="hourly-forecast-date">{ date }</p>
+            <p>{ hour.time.time_of_day() }</p>
+            <img src=icon_path title=description/>
+            <p>{ temperature }</p>
+            <p>{ precipitation }</p>
+            <p>{ wind_speed } <WindDirection bearing=MaybeSignal::Static(hour.wind_direction) locale=locale/></p>
+        </div>
+    }
+}
+
+#[component]
+fn DailyForecastList<F>(daily_forecast: F, unit_system: ReadSignal<UnitSystem>, locale: Signal<Locale>) -> impl IntoView where F: Fn() -> Vec<DailyForecast> + 'static {
+    view! {
+        <div class="card" id="daily-forecast-list">
+            <For
+                each = daily_forecast
+                key = |day| day.date
+                children = move |day| view! { <DailyForecastEntry day=day unit_system=unit_system locale=locale /> }
+            />
+        </div>
+    }
+}
+
+#[component]
+fn DailyForecastEntry(day: DailyForecast, unit_system: ReadSignal<UnitSystem>, locale: Signal<Locale>) -> impl IntoView {
+    let min_temperature = move || format_measurement(day.min_temperature, unit_system());
+    let max_temperature = move || format_measurement(day.max_temperature, unit_system());
+    let precipitation_sum = move || format_measurement(day.precipitation_sum, unit_system());
+    let max_wind_speed = move || format_measurement(day.max_wind_speed, unit_system());
+    let weather = day.weather;
+    let icon_path = weather.icon_path.clone();
+    let description = Signal::derive(move || weather.description(locale()));
+
+    let format_time = |time: Option<DateTime>| {
+        time.map(|time| time.time_of_day()).unwrap_or_else(|| "–".to_owned())
+    };
+
+    view! {
+        <div class="daily-forecast-entry">
+            <p>{ day.date.to_string() }</p>
+            <img src=icon_path title=description/>
+            <p>{ description }</p>
+            <p>{ min_temperature } / { max_temperature }</p>
+            <p>{ precipitation_sum }</p>
+            <p>{ max_wind_speed } <WindDirection bearing=MaybeSignal::Static(day.dominant_wind_direction) locale=locale/></p>
+            <p>{ format_time(day.sunrise) } { " – " } { format_time(day.sunset) }</p>
+        </div>
+    }
+}
+
+/// Wind directions are reported in 16 points, which the exact bearing of the arrow refines.
+const WIND_DIRECTION_RESOLUTION: CompassResolution = CompassResolution::SixteenPoints;
+
+/// Arrow rotated to where the wind is blowing, followed by the compass point it is coming from.
+#[component]
+fn WindDirection(bearing: MaybeSignal<Bearing>, locale: Signal<Locale>) -> impl IntoView {
+    let compass_direction = move || bearing().compass_direction(WIND_DIRECTION_RESOLUTION);
+    let title = move || format!("{} ({:.0})", compass_direction().name(locale()), bearing());
+    let rotation = move || format!("rotate({} 12 12)", f32::from(bearing().opposite()));
+
+    view! {
+        <span class="wind-direction" title=title>
+            <svg class="wind-direction-arrow" viewBox="0 0 24 24" aria-hidden="true">
+                <path d="M12 2 L19 21 L12 17 L5 21 Z" transform=rotation/>
+            </svg>
+            { move || compass_direction().abbreviation(locale()) }
+        </span>
+    }
+}
+
+/// Name and attribution URL of the source of the weather data shown.
+#[component]
+fn Footer(attribution: Signal<(&'static str, &'static str)>, locale: Signal<Locale>) -> impl IntoView {
     view! {
-        <a href="https://open-meteo.com/">Weather data by Open-Meteo.com</a>
+        <a href={ move || attribution().1 }>{ text(locale, Text::WeatherDataBy) } " " { move || attribution().0 }</a>
     }
 }
@@ -1,3 +1,6 @@
+use std::future::Future;
+use std::pin::Pin;
+
 mod always_equal;
 pub use always_equal::AlwaysEqual;
 
@@ -5,3 +8,5 @@ mod never_equal;
 pub use never_equal::NeverEqual;
 
 pub type CloneableStr = leptos::Oco<'static, str>;
+
+pub type LocalBoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;